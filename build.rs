@@ -0,0 +1,13 @@
+//! Embed the git commit and rustc version for `pam version --verbose`
+
+use vergen_gitcl::{Emitter, GitclBuilder, RustcBuilder};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Outside a git checkout (e.g. a source tarball) vergen falls back to
+    // placeholder values instead of failing the build
+    Emitter::default()
+        .add_instructions(&GitclBuilder::default().sha(true).build()?)?
+        .add_instructions(&RustcBuilder::default().semver(true).build()?)?
+        .emit()?;
+    Ok(())
+}
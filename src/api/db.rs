@@ -0,0 +1,71 @@
+//! Direct PostgreSQL access, bypassing the HTTP API
+//!
+//! Used for debugging when the API is down but the database is reachable.
+
+use anyhow::{Context, Result};
+use tokio_postgres::NoTls;
+
+use crate::api::client::{MemoryStatus, TableInfo};
+use crate::config::Config;
+
+// The backend's schema isn't versioned with the CLI, so each total is read
+// from the table with this name, or failing that the one table ending in
+// `_<name>` (e.g. `cos_memories`)
+const MEMORIES_TABLE: &str = "memories";
+const SESSIONS_TABLE: &str = "sessions";
+const REFLECTIONS_TABLE: &str = "reflections";
+
+async fn connect(config: &Config) -> Result<tokio_postgres::Client> {
+    let (client, connection) = tokio_postgres::connect(&config.db_connection_string(), NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to {}:{}", config.db_host, config.db_port))?;
+
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::warn!("Database connection error: {}", e);
+        }
+    });
+
+    Ok(client)
+}
+
+/// Compute memory status straight from the database tables
+pub async fn get_memory_status(config: &Config) -> Result<MemoryStatus> {
+    let client = connect(config).await?;
+
+    let rows = client
+        .query(
+            "SELECT table_name FROM information_schema.tables \
+             WHERE table_schema = 'public' ORDER BY table_name",
+            &[],
+        )
+        .await?;
+
+    let mut tables = Vec::new();
+    for row in rows {
+        let name: String = row.get(0);
+        let query = format!("SELECT count(*) FROM \"{}\"", name.replace('"', "\"\""));
+        let row_count: i64 = client.query_one(query.as_str(), &[]).await?.get(0);
+        tables.push(TableInfo { name, row_count });
+    }
+
+    // Missing or ambiguous tables give `None`, shown as "unknown" rather than 0
+    let count_of = |table: &str| {
+        if let Some(exact) = tables.iter().find(|t| t.name == table) {
+            return Some(exact.row_count);
+        }
+        let suffix = format!("_{}", table);
+        let mut matches = tables.iter().filter(|t| t.name.ends_with(&suffix));
+        match (matches.next(), matches.next()) {
+            (Some(only), None) => Some(only.row_count),
+            _ => None,
+        }
+    };
+
+    Ok(MemoryStatus {
+        total_memories: count_of(MEMORIES_TABLE),
+        total_sessions: count_of(SESSIONS_TABLE),
+        total_reflections: count_of(REFLECTIONS_TABLE),
+        tables,
+    })
+}
@@ -1,3 +1,5 @@
 //! API client modules
 
 pub mod client;
+pub mod db;
+pub mod timing;
@@ -0,0 +1,73 @@
+//! Request timings for `--timing`
+//!
+//! reqwest doesn't expose per-phase timings, so this records what it can see
+//! from outside: DNS lookups (through a wrapping resolver) and time to
+//! response headers for every request. Connect and TLS time are part of first
+//! byte for requests that opened a connection.
+//!
+//! Lookups say nothing reliable about connection reuse: a proxy, an IP-literal
+//! `api_url`, or a caching resolver all change how many there are.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+use hyper::client::connect::dns::Name;
+use reqwest::dns::{Addrs, Resolve, Resolving};
+
+/// One request as seen by `send`
+struct RequestTiming {
+    method: String,
+    path: String,
+    status: Option<u16>,
+    first_byte: Duration,
+}
+
+static REQUESTS: Mutex<Vec<RequestTiming>> = Mutex::new(Vec::new());
+
+/// Host and duration of each DNS lookup
+static LOOKUPS: Mutex<Vec<(String, Duration)>> = Mutex::new(Vec::new());
+
+pub(crate) fn record_request(method: &reqwest::Method, url: &reqwest::Url, status: Option<u16>, first_byte: Duration) {
+    let timing = RequestTiming {
+        method: method.to_string(),
+        path: url.path().to_string(),
+        status,
+        first_byte,
+    };
+    REQUESTS.lock().unwrap_or_else(|e| e.into_inner()).push(timing);
+}
+
+/// System resolver that records how long each lookup takes
+pub(crate) struct TimingResolver;
+
+impl Resolve for TimingResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let start = Instant::now();
+            let addrs = tokio::net::lookup_host((host.as_str(), 0)).await.map(|addrs| addrs.collect::<Vec<_>>());
+            LOOKUPS.lock().unwrap_or_else(|e| e.into_inner()).push((host, start.elapsed()));
+
+            let addrs: Addrs = Box::new(addrs?.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Print the timing breakdown to stderr, keeping stdout clean for `--json`
+pub fn report(total: Duration) {
+    let requests = REQUESTS.lock().unwrap_or_else(|e| e.into_inner());
+    let lookups = LOOKUPS.lock().unwrap_or_else(|e| e.into_inner());
+
+    let dns: Duration = lookups.iter().map(|(_, d)| *d).sum();
+
+    eprintln!();
+    eprintln!("{} {} requests", "Timing:".bold(), requests.len());
+    eprintln!("  DNS:        {:>6}ms ({} lookups)", dns.as_millis(), lookups.len());
+    for r in requests.iter() {
+        let status = r.status.map_or_else(|| "ERR".to_string(), |s| s.to_string());
+        eprintln!("  First byte: {:>6}ms  {} {} {}", r.first_byte.as_millis(), status, r.method, r.path);
+    }
+    eprintln!("  Total:      {:>6}ms", total.as_millis());
+}
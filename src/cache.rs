@@ -0,0 +1,142 @@
+//! Small on-disk JSON cache stored under the config directory
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crate::theme::Themed;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::path::PathBuf;
+
+use crate::config::Config;
+
+/// A cached payload stamped with when and where it was fetched
+#[derive(Serialize, Deserialize)]
+pub struct Entry<T> {
+    pub fetched_at: DateTime<Utc>,
+    pub source: String,
+    pub data: T,
+}
+
+impl<T> Entry<T> {
+    pub fn age(&self) -> chrono::Duration {
+        Utc::now().signed_duration_since(self.fetched_at)
+    }
+}
+
+fn path(name: &str) -> Result<PathBuf> {
+    let dir = Config::config_dir()?.join("cache");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir.join(format!("{}.json", name)))
+}
+
+/// Read a cache entry regardless of age, if it was fetched from `source`
+pub fn read<T: DeserializeOwned>(name: &str, source: &str) -> Option<Entry<T>> {
+    let content = std::fs::read_to_string(path(name).ok()?).ok()?;
+    let entry: Entry<T> = serde_json::from_str(&content).ok()?;
+    (entry.source == source).then_some(entry)
+}
+
+/// Read a cache entry only if it is younger than `ttl_secs`
+pub fn read_fresh<T: DeserializeOwned>(name: &str, source: &str, ttl_secs: u64) -> Option<T> {
+    let entry = read::<T>(name, source)?;
+    let age = entry.age().num_seconds();
+    (age >= 0 && (age as u64) < ttl_secs).then_some(entry.data)
+}
+
+/// Write a cache entry, stamping it with the current time
+pub fn write<T: Serialize>(name: &str, source: &str, data: &T) -> Result<()> {
+    let entry = Entry {
+        fetched_at: Utc::now(),
+        source: source.to_string(),
+        data,
+    };
+    std::fs::write(path(name)?, serde_json::to_string(&entry)?)?;
+    Ok(())
+}
+
+/// API data, either fetched just now or served from the cache
+pub struct Fetched<T> {
+    pub data: T,
+    /// Age of the cached copy, or `None` when the data is current
+    pub cached_age: Option<chrono::Duration>,
+}
+
+impl<T> Fetched<T> {
+    pub fn fresh(data: T) -> Self {
+        Self { data, cached_age: None }
+    }
+
+    fn from_entry(entry: Entry<T>) -> Self {
+        Self { cached_age: Some(entry.age()), data: entry.data }
+    }
+
+    /// Label for stale data, e.g. `cached (12m old)`
+    pub fn cached_label(&self) -> Option<String> {
+        self.cached_age.map(|age| format!("cached ({} old)", format_age(age)))
+    }
+
+    /// Warn on stderr when the data came from the cache, so piped output stays clean
+    pub fn announce(&self) {
+        if let Some(label) = self.cached_label() {
+            eprintln!("{} Offline: showing {}", "⚠".warning(), label);
+        }
+    }
+}
+
+/// Compact age like `45s`, `12m`, `3h`, or `2d`
+pub fn format_age(age: chrono::Duration) -> String {
+    if age.num_minutes() < 1 {
+        format!("{}s", age.num_seconds().max(0))
+    } else if age.num_hours() < 1 {
+        format!("{}m", age.num_minutes())
+    } else if age.num_days() < 1 {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}d", age.num_days())
+    }
+}
+
+/// Await `fetch`, falling back to `cached()` in `--offline` mode or when the
+/// backend can't be reached. Other errors (e.g. a 4xx) are returned as-is.
+pub async fn with_fallback<T>(
+    config: &Config,
+    fetch: impl Future<Output = Result<T>>,
+    cached: impl FnOnce() -> Option<Entry<T>>,
+) -> Result<Fetched<T>> {
+    if config.offline {
+        return cached()
+            .map(Fetched::from_entry)
+            .ok_or_else(|| anyhow::anyhow!("offline and nothing cached yet - run this once while online"));
+    }
+
+    match fetch.await {
+        Ok(data) => Ok(Fetched::fresh(data)),
+        Err(e) if crate::api::client::is_network_error(&e) => match cached() {
+            Some(entry) => {
+                tracing::debug!("Serving cached data after network error: {}", e);
+                Ok(Fetched::from_entry(entry))
+            }
+            None => Err(e),
+        },
+        Err(e) => Err(e),
+    }
+}
+
+/// [`with_fallback`] for a plain API response, writing every successful
+/// fetch through to the cache entry `name`.
+pub async fn fetch_or_cached<T: Serialize + DeserializeOwned>(
+    name: &str,
+    config: &Config,
+    fetch: impl Future<Output = Result<T>>,
+) -> Result<Fetched<T>> {
+    let source = &config.api_url;
+    let fetch = async {
+        let data = fetch.await?;
+        if let Err(e) = write(name, source, &data) {
+            tracing::debug!("Failed to write {} cache: {}", name, e);
+        }
+        Ok(data)
+    };
+    with_fallback(config, fetch, || read(name, source)).await
+}
@@ -1,104 +1,265 @@
 //! Interactive chat with PAM
 
+use std::path::PathBuf;
+
 use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
 use colored::Colorize;
-use dialoguer::Input;
+use crate::theme::Themed;
+use dialoguer::{Confirm, Editor, Input};
+use serde::{Deserialize, Serialize};
 
 use crate::config::Config;
+use crate::ui;
+use crate::ChatAction;
 use crate::api;
+use crate::commands::context;
+
+/// Local record of chat sessions, kept under the config directory
+const SESSIONS_FILE: &str = "chat_sessions.json";
+
+/// Messages waiting to be sent while the chat service is down (`--queue`)
+const OUTBOX_FILE: &str = "chat_outbox.json";
+
+/// Sessions `chat history` shows without `--limit` or `default_limit`
+const HISTORY_LIMIT: usize = 20;
+
+/// Cap on the combined size of `--file` attachments, in characters
+const MAX_ATTACHMENT_CHARS: usize = 100_000;
+
+/// Session and delivery options for `pam chat`
+pub struct ChatOptions {
+    /// Continue the latest session
+    pub continue_session: bool,
+    /// Continue the local session with this friendly name or id
+    pub session: Option<String>,
+    /// Explicit session id to use instead of generating one
+    pub session_id: Option<String>,
+    pub name: Option<String>,
+    pub retries: u32,
+    pub files: Vec<String>,
+    /// Custom system prompt sent with every message
+    pub system: Option<String>,
+    /// Refresh the context bundle first if it is stale
+    pub refresh_before: bool,
+    /// Queue messages locally when the chat service is unavailable
+    pub queue: bool,
+    /// Only send the queued messages
+    pub flush: bool,
+}
+
+pub fn handle_action(action: ChatAction, config: &Config, quiet: bool) -> Result<()> {
+    match action {
+        ChatAction::History { limit } => history(config.limit(limit, HISTORY_LIMIT), quiet),
+    }
+}
 
 pub async fn handle(
     message: Option<String>,
     user: Option<String>,
-    continue_session: bool,
+    options: ChatOptions,
     config: &Config,
     verbose: bool,
+    quiet: bool,
 ) -> Result<()> {
-    let user_email = user.or(config.user_email.clone()).unwrap_or_else(|| {
-        println!("{} No user email specified. Use --user or set PAM_USER_EMAIL", "⚠".yellow());
+    if options.flush {
+        if flush_outbox(&config.api_url, quiet).await? && !quiet {
+            println!("{} No queued messages left", "✓".success());
+        }
+        return Ok(());
+    }
+
+    let user_email = config.resolve_user_email(user).unwrap_or_else(|| {
+        println!(
+            "{} No user email specified. Use --user, set PAM_USER_EMAIL, or set git's user.email",
+            "⚠".warning()
+        );
         "unknown@mergeworld.com".to_string()
     });
 
+    let ChatOptions { continue_session, session, session_id, name, retries, files, system, refresh_before, queue, .. } = options;
+    ui::install_interrupt_handler();
+
+    let attachments = match read_attachments(&files, quiet) {
+        Ok(attachments) => attachments,
+        Err(e) => {
+            ui::fail(e);
+            return Ok(());
+        }
+    };
+
+    if let Some(id) = &session_id {
+        if let Err(e) = validate_session_id(id) {
+            ui::fail_error("Invalid --session-id", &e);
+            return Ok(());
+        }
+    }
+
     // Get or create session ID
-    let session_id = if continue_session {
+    let session_id = match session.as_deref() {
+        // A specific session, by friendly name or id
+        Some(target) => match find_session(target) {
+            Some(sid) => {
+                println!("{} Continuing session: {}", "•".primary(), sid);
+                sid
+            }
+            None => {
+                ui::fail(format_args!("No local session named '{}'. See `pam chat history`.", target));
+                return Ok(());
+            }
+        },
         // Try to get most recent session
-        match api::client::get_latest_session(&config.api_url, &user_email).await {
+        None if continue_session => match api::client::get_latest_session(&config.api_url, &user_email).await {
             Ok(Some(sid)) => {
-                println!("{} Continuing session: {}", "•".cyan(), sid);
+                println!("{} Continuing session: {}", "•".primary(), sid);
                 sid
             }
-            _ => {
-                println!("{} No previous session found, starting new one", "•".cyan());
+            Ok(None) => {
+                println!("{} No previous session found, starting new one", "•".primary());
                 generate_session_id()
             }
-        }
-    } else {
-        generate_session_id()
+            Err(e) => {
+                ui::fail_error("Couldn't reach the session service", &e);
+                println!("{} {}", "→".dimmed(), "Run without --continue-session to start a new session".dimmed());
+                return Ok(());
+            }
+        },
+        None => session_id.unwrap_or_else(generate_session_id),
     };
 
+    if let Some(name) = name {
+        name_session(&session_id, &user_email, &name)?;
+        if !quiet {
+            println!("{} Session named '{}'", "✓".success(), name);
+        }
+    }
+
+    if refresh_before {
+        context::refresh_if_stale(config, verbose, quiet, false).await;
+    }
+
     if let Some(msg) = message {
         // Single message mode
-        send_message(&config.api_url, &user_email, &session_id, &msg, verbose).await
+        let full_message = format!("{}{}", attachments, msg);
+        let outgoing = Outgoing {
+            session_id: session_id.clone(),
+            user_email: user_email.clone(),
+            display: msg,
+            message: full_message,
+            system,
+            queued_at: Utc::now(),
+        };
+        send_message(&config.api_url, outgoing, retries, queue, verbose, quiet).await
     } else {
         // Interactive mode
-        interactive_chat(&config.api_url, &user_email, &session_id, verbose).await
+        interactive_chat(&config.api_url, &user_email, &session_id, attachments, system, queue, verbose, quiet).await
     }
 }
 
+/// Send one message, echoing its `display` text. With `queue`, a message the
+/// service can't take right now goes to the outbox instead of being lost.
 async fn send_message(
     api_url: &str,
-    user_email: &str,
-    session_id: &str,
-    message: &str,
+    outgoing: Outgoing,
+    retries: u32,
+    queue: bool,
     verbose: bool,
+    quiet: bool,
 ) -> Result<()> {
-    if verbose {
-        println!("Session: {}", session_id);
-        println!("User: {}", user_email);
-        println!("Message: {}", message);
+    // Earlier messages go first; if they still can't be sent, this one waits behind them
+    if !flush_outbox(api_url, quiet).await? && queue {
+        return queue_message(outgoing);
     }
 
-    println!("{} {}", "You:".bold(), message);
-    println!();
+    if verbose {
+        println!("Session: {}", outgoing.session_id);
+        println!("User: {}", outgoing.user_email);
+        if let Some(system) = &outgoing.system {
+            println!("System: {}", system);
+        }
+        println!("Message: {}", outgoing.message);
+    }
 
-    // Show thinking indicator
-    print!("{}", "PAM is thinking...".dimmed());
-    std::io::Write::flush(&mut std::io::stdout())?;
+    if !quiet {
+        println!("{} {}", "You:".bold(), outgoing.display);
+        println!();
+    }
 
-    match api::client::chat(&api_url, user_email, session_id, message).await {
-        Ok(response) => {
-            // Clear thinking indicator
-            print!("\r{}", " ".repeat(20));
-            print!("\r");
+    let mut attempt = 0;
+    loop {
+        let spinner = ui::spinner("PAM is thinking...", quiet);
+        let outcome = api::client::chat(
+            api_url,
+            &outgoing.user_email,
+            &outgoing.session_id,
+            &outgoing.message,
+            outgoing.system.as_deref(),
+        )
+        .await;
+        spinner.finish_and_clear();
 
-            println!("{}", "PAM:".bold().cyan());
-            println!("{}", response);
-        }
-        Err(e) => {
-            print!("\r");
-            println!("{} Chat failed: {}", "✗".red(), e);
+        match outcome {
+            Ok(response) => {
+                if !quiet {
+                    println!("{}", "PAM:".bold().primary());
+                }
+                println!("{}", response);
+                record_message(&outgoing.session_id, &outgoing.user_email);
+            }
+            Err(e) if attempt < retries && api::client::is_network_error(&e) => {
+                attempt += 1;
+                println!("{} Chat failed: {} (retrying {}/{})", "⚠".warning(), e, attempt, retries);
+                tokio::time::sleep(std::time::Duration::from_secs(attempt.into())).await;
+                continue;
+            }
+            Err(e) if queue && api::client::is_retryable(&e) => {
+                println!("{} {}", "⚠".warning(), e);
+                return queue_message(outgoing);
+            }
+            Err(e) => {
+                ui::fail_error("Chat failed", &e);
+            }
         }
+        break;
     }
 
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn interactive_chat(
     api_url: &str,
     user_email: &str,
     session_id: &str,
-    verbose: bool,
+    mut attachments: String,
+    mut system: Option<String>,
+    queue: bool,
+    _verbose: bool,
+    quiet: bool,
 ) -> Result<()> {
-    println!("{}", "╔════════════════════════════════════════════════════════════╗".cyan());
-    println!("{}", "║  PAM Chief of Staff - Interactive Chat                     ║".cyan());
-    println!("{}", "║  Type 'quit' or 'exit' to end, 'clear' to reset session    ║".cyan());
-    println!("{}", "╚════════════════════════════════════════════════════════════╝".cyan());
-    println!();
-    println!("Session: {}", session_id.dimmed());
-    println!("User: {}", user_email.dimmed());
-    println!();
+    if !quiet {
+        println!("{}", "╔════════════════════════════════════════════════════════════╗".primary());
+        println!("{}", "║  PAM Chief of Staff - Interactive Chat                     ║".primary());
+        println!("{}", "║  Type 'quit' or 'exit' to end, 'clear' to reset session    ║".primary());
+        println!("{}", "╚════════════════════════════════════════════════════════════╝".primary());
+        println!();
+        match session_name(session_id) {
+            Some(name) => println!("Session: {} ({})", name.bold(), session_id.dimmed()),
+            None => println!("Session: {}", session_id.dimmed()),
+        }
+        println!("User: {}", user_email.dimmed());
+        if let Some(system) = &system {
+            println!("System: {}", ui::truncate_display(system, 60).dimmed());
+        }
+        println!();
+    }
 
     let mut current_session = session_id.to_string();
+    let mut last_response: Option<String> = None;
+    // Last message sent (with any attachments), for `/retry`
+    let mut last_message: Option<String> = None;
+    // Kept alive for the session: on X11 the copied text disappears when the owner is dropped
+    let mut clipboard: Option<arboard::Clipboard> = None;
 
     loop {
         let input: String = Input::new()
@@ -107,15 +268,51 @@ async fn interactive_chat(
 
         let trimmed = input.trim();
 
+        if trimmed == "/name" || trimmed.starts_with("/name ") {
+            let label = trimmed["/name".len()..].trim();
+            if label.is_empty() {
+                match session_name(&current_session) {
+                    Some(name) => println!("Session name: {}", name.bold()),
+                    None => println!("{}", "Usage: /name <label>".warning()),
+                }
+            } else {
+                match name_session(&current_session, user_email, label) {
+                    Ok(()) => println!("{} Session named '{}'", "✓".success(), label),
+                    Err(e) => println!("{} Could not name session: {}", "✗".error(), e),
+                }
+            }
+            continue;
+        }
+
+        if trimmed == "/system" || trimmed.starts_with("/system ") {
+            match trimmed["/system".len()..].trim() {
+                "" => match &system {
+                    Some(prompt) => println!("System prompt: {}", prompt),
+                    None => println!("{}", "No custom system prompt. Usage: /system <prompt> | /system off".warning()),
+                },
+                "off" => {
+                    system = None;
+                    println!("{} Using PAM's default system prompt", "✓".success());
+                }
+                prompt => {
+                    system = Some(prompt.to_string());
+                    println!("{} System prompt updated", "✓".success());
+                }
+            }
+            continue;
+        }
+
         // Handle special commands
-        match trimmed.to_lowercase().as_str() {
+        let command = trimmed.to_lowercase();
+        let retry = matches!(command.as_str(), "/retry" | "!!");
+        let message = match command.as_str() {
             "quit" | "exit" | "q" => {
-                println!("\n{} Goodbye!", "👋".to_string());
+                println!("\n👋 Goodbye!");
                 break;
             }
             "clear" => {
                 current_session = generate_session_id();
-                println!("{} Started new session: {}", "✓".green(), current_session);
+                println!("{} Started new session: {}", "✓".success(), current_session);
                 continue;
             }
             "help" => {
@@ -123,68 +320,486 @@ async fn interactive_chat(
                 continue;
             }
             "/reflect" => {
-                println!("{}", "Generating reflection...".dimmed());
                 // Trigger reflection
-                match api::client::generate_reflection(api_url, user_email, &[current_session.clone()]).await {
+                let spinner = ui::timed_spinner("Generating reflection...", quiet);
+                let outcome = api::client::generate_reflection(api_url, user_email, &[current_session.clone()]).await;
+                spinner.finish_and_clear();
+                match outcome {
                     Ok(reflection) => {
-                        println!("\n{}", "Reflection:".bold().cyan());
+                        println!("\n{}", "Reflection:".bold().primary());
                         for learning in &reflection.learnings {
                             println!("  💡 {}", learning);
                         }
                     }
-                    Err(e) => println!("{} Reflection failed: {}", "✗".red(), e),
+                    Err(e) => println!("{} Reflection failed: {}", "✗".error(), e),
                 }
                 continue;
             }
             "/status" => {
                 println!("Session: {}", current_session);
+                if let Some(name) = session_name(&current_session) {
+                    println!("Name: {}", name);
+                }
                 println!("User: {}", user_email);
+                if let Some(system) = &system {
+                    println!("System: {}", system);
+                }
+                continue;
+            }
+            "/copy" => {
+                match &last_response {
+                    Some(response) => match copy_to_clipboard(&mut clipboard, response) {
+                        Ok(()) => println!("{} Copied last response to clipboard", "✓".success()),
+                        Err(e) => println!("{} Clipboard unavailable: {}", "✗".error(), e),
+                    },
+                    None => println!("{}", "No response to copy yet.".warning()),
+                }
                 continue;
             }
+            "/retry" | "!!" => match &last_message {
+                Some(previous) => {
+                    println!("{} Resending your last message", "↻".primary());
+                    previous.clone()
+                }
+                None => {
+                    println!("{}", "No message to resend yet.".warning());
+                    continue;
+                }
+            },
+            "/multi" => match read_multiline()? {
+                Some(text) => text,
+                None => {
+                    println!("{}", "Empty message, nothing sent.".warning());
+                    continue;
+                }
+            },
             "" => continue,
-            _ => {}
-        }
+            _ => trimmed.to_string(),
+        };
 
-        // Send message to PAM
+        // Attachments ride along with the first message only; a retry already carries them
+        let message = if retry || attachments.is_empty() {
+            message
+        } else {
+            format!("{}{}", attachments, message)
+        };
+        last_message = Some(message.clone());
+
+        // Queued messages go first; if they still can't be sent, this one joins them
         println!();
-        print!("{}", "PAM is thinking...".dimmed());
-        std::io::Write::flush(&mut std::io::stdout())?;
+        if !flush_outbox(api_url, quiet).await? && queue {
+            queue_message(Outgoing {
+                session_id: current_session.clone(),
+                user_email: user_email.to_string(),
+                display: trimmed.to_string(),
+                message,
+                system: system.clone(),
+                queued_at: Utc::now(),
+            })?;
+            attachments.clear();
+            println!();
+            continue;
+        }
 
-        match api::client::chat(api_url, user_email, &current_session, trimmed).await {
-            Ok(response) => {
-                // Clear thinking indicator
-                print!("\r{}", " ".repeat(20));
-                print!("\r");
+        // Send message to PAM, offering to resend it after network errors
+        loop {
+            let spinner = ui::spinner("PAM is thinking...", quiet);
+            let outcome = api::client::chat(api_url, user_email, &current_session, &message, system.as_deref()).await;
+            spinner.finish_and_clear();
 
-                println!("{}", "PAM:".bold().cyan());
-                println!("{}", response);
-                println!();
+            match outcome {
+                Ok(response) => {
+                    println!("{}", "PAM:".bold().primary());
+                    println!("{}", response);
+                    println!();
+                    last_response = Some(response);
+                    record_message(&current_session, user_email);
+                    attachments.clear();
+                }
+                Err(e) if queue && api::client::is_retryable(&e) => {
+                    println!("{} Error: {}", "⚠".warning(), e);
+                    queue_message(Outgoing {
+                        session_id: current_session.clone(),
+                        user_email: user_email.to_string(),
+                        display: trimmed.to_string(),
+                        message: message.clone(),
+                        system: system.clone(),
+                        queued_at: Utc::now(),
+                    })?;
+                    attachments.clear();
+                    println!();
+                }
+                Err(e) => {
+                    println!("{} Error: {}", "✗".error(), e);
+                    if api::client::is_network_error(&e)
+                        && Confirm::new().with_prompt("Resend this message?").default(true).interact()?
+                    {
+                        continue;
+                    }
+                    println!();
+                }
+            }
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn copy_to_clipboard(clipboard: &mut Option<arboard::Clipboard>, text: &str) -> Result<()> {
+    if clipboard.is_none() {
+        *clipboard = Some(arboard::Clipboard::new()?);
+    }
+    if let Some(cb) = clipboard.as_mut() {
+        cb.set_text(text)?;
+    }
+    Ok(())
+}
+
+/// Read a multiline message from `$EDITOR`, falling back to typing lines until a lone `.`
+fn read_multiline() -> Result<Option<String>> {
+    let text = match Editor::new().edit("") {
+        Ok(text) => text,
+        Err(e) => {
+            println!("{} Could not open editor ({}), enter lines and finish with '.'", "⚠".warning(), e);
+            let mut lines = Vec::new();
+            for line in std::io::stdin().lines() {
+                let line = line?;
+                if line.trim() == "." {
+                    break;
+                }
+                lines.push(line);
+            }
+            Some(lines.join("\n"))
+        }
+    };
+
+    Ok(text.map(|t| t.trim().to_string()).filter(|t| !t.is_empty()))
+}
+
+/// Read `--file` attachments into a block to prepend to a message.
+///
+/// Files are wrapped in `--- file: name ---` markers. Content beyond
+/// `MAX_ATTACHMENT_CHARS` in total is cut off with a warning.
+fn read_attachments(files: &[String], quiet: bool) -> Result<String> {
+    let mut block = String::new();
+    let mut remaining = MAX_ATTACHMENT_CHARS;
+
+    for path in files {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path, e))?;
+        let name = std::path::Path::new(path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.clone());
+
+        if remaining == 0 {
+            println!("{} Skipping {}: attachment limit of {} characters reached", "⚠".warning(), name, MAX_ATTACHMENT_CHARS);
+            continue;
+        }
+
+        let chars = content.chars().count();
+        let content = if chars > remaining {
+            println!(
+                "{} Truncated {} to {} of {} characters (attachment limit {})",
+                "⚠".warning(),
+                name,
+                remaining,
+                chars,
+                MAX_ATTACHMENT_CHARS
+            );
+            ui::truncate_display(&content, remaining)
+        } else {
+            content
+        };
+        remaining -= content.chars().count().min(remaining);
+
+        if !quiet {
+            println!("📎 Attached {} ({} characters)", name, content.chars().count());
+        }
+        block.push_str(&format!("--- file: {} ---\n{}\n--- end file ---\n\n", name, content.trim_end()));
+    }
+
+    Ok(block)
+}
+
+/// A chat session started from this machine
+#[derive(Serialize, Deserialize)]
+struct SessionRecord {
+    id: String,
+    #[serde(default)]
+    name: Option<String>,
+    user_email: String,
+    started_at: DateTime<Utc>,
+    last_active: DateTime<Utc>,
+    #[serde(default)]
+    messages: usize,
+}
+
+fn sessions_path() -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join(SESSIONS_FILE))
+}
+
+fn load_sessions() -> Vec<SessionRecord> {
+    sessions_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_sessions(sessions: &[SessionRecord]) -> Result<()> {
+    std::fs::write(sessions_path()?, serde_json::to_string_pretty(sessions)?)?;
+    Ok(())
+}
+
+/// Find the record for `id`, creating it if this is the first time we see it
+fn upsert_session<'a>(sessions: &'a mut Vec<SessionRecord>, id: &str, user_email: &str) -> &'a mut SessionRecord {
+    match sessions.iter().position(|s| s.id == id) {
+        Some(i) => &mut sessions[i],
+        None => {
+            let now = Utc::now();
+            sessions.push(SessionRecord {
+                id: id.to_string(),
+                name: None,
+                user_email: user_email.to_string(),
+                started_at: now,
+                last_active: now,
+                messages: 0,
+            });
+            sessions.last_mut().expect("just pushed")
+        }
+    }
+}
+
+/// Note a successful exchange in the local history; failures are only logged
+fn record_message(id: &str, user_email: &str) {
+    let mut sessions = load_sessions();
+    let record = upsert_session(&mut sessions, id, user_email);
+    record.last_active = Utc::now();
+    record.messages += 1;
+
+    if let Err(e) = save_sessions(&sessions) {
+        tracing::debug!("Failed to record chat session: {}", e);
+    }
+}
+
+/// Attach a friendly name to a session, taking it from any session that had it
+fn name_session(id: &str, user_email: &str, name: &str) -> Result<()> {
+    let mut sessions = load_sessions();
+    for session in sessions.iter_mut().filter(|s| s.id != id) {
+        if session.name.as_deref() == Some(name) {
+            session.name = None;
+        }
+    }
+    upsert_session(&mut sessions, id, user_email).name = Some(name.to_string());
+    save_sessions(&sessions)
+}
+
+fn session_name(id: &str) -> Option<String> {
+    load_sessions().into_iter().find(|s| s.id == id)?.name
+}
+
+/// Resolve a session by friendly name, falling back to an exact id match
+fn find_session(name_or_id: &str) -> Option<String> {
+    let sessions = load_sessions();
+    sessions
+        .iter()
+        .find(|s| s.name.as_deref() == Some(name_or_id))
+        .or_else(|| sessions.iter().find(|s| s.id == name_or_id))
+        .map(|s| s.id.clone())
+}
+
+/// A chat message on its way out, as stored in the outbox
+#[derive(Serialize, Deserialize)]
+struct Outgoing {
+    session_id: String,
+    user_email: String,
+    /// What the user typed, shown when the reply arrives
+    display: String,
+    /// What is sent: `display` plus any attachments
+    message: String,
+    system: Option<String>,
+    queued_at: DateTime<Utc>,
+}
+
+fn outbox_path() -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join(OUTBOX_FILE))
+}
+
+fn load_outbox() -> Vec<Outgoing> {
+    outbox_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_outbox(queued: &[Outgoing]) -> Result<()> {
+    let path = outbox_path()?;
+    if queued.is_empty() {
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        return Ok(());
+    }
+    std::fs::write(path, serde_json::to_string_pretty(queued)?)?;
+    Ok(())
+}
+
+/// Add a message to the outbox and say plainly that it hasn't been answered.
+///
+/// Counts as a failure for `--strict`: nothing was answered yet.
+fn queue_message(outgoing: Outgoing) -> Result<()> {
+    let mut queued = load_outbox();
+    queued.push(outgoing);
+    save_outbox(&queued)?;
+
+    ui::mark_failed();
+    println!(
+        "{} Message queued, NOT answered yet ({} waiting). It is sent the next time chat connects, or run {}.",
+        "⚠".warning(),
+        queued.len(),
+        "pam chat --flush".primary()
+    );
+    Ok(())
+}
+
+/// Send queued messages oldest first, printing each reply.
+///
+/// Stops at the first retryable failure so the rest keep their order; a
+/// message the server rejects outright is dropped, with its text printed so
+/// it isn't lost. Returns whether the outbox is empty afterwards.
+async fn flush_outbox(api_url: &str, quiet: bool) -> Result<bool> {
+    let queued = load_outbox();
+    if queued.is_empty() {
+        return Ok(true);
+    }
+    if !quiet {
+        println!("{} Sending {} queued message(s)", "↻".primary(), queued.len());
+    }
+
+    for (i, entry) in queued.iter().enumerate() {
+        let spinner = ui::spinner("PAM is thinking...", quiet);
+        let outcome = api::client::chat(
+            api_url,
+            &entry.user_email,
+            &entry.session_id,
+            &entry.message,
+            entry.system.as_deref(),
+        )
+        .await;
+        spinner.finish_and_clear();
+
+        let queued_at = entry.queued_at.with_timezone(&Local).format("%Y-%m-%d %H:%M");
+        match outcome {
+            Ok(response) => {
+                // Saved before printing, so only a Ctrl-C while the request is
+                // in flight can send this message twice
+                save_outbox(&queued[i + 1..])?;
+                record_message(&entry.session_id, &entry.user_email);
+                println!("{} {} {}", "You:".bold(), entry.display, format!("(queued {})", queued_at).dimmed());
+                println!("{}", "PAM:".bold().primary());
+                println!("{}\n", response);
             }
+            // Only a message the server refuses outright is dropped
+            Err(e) if api::client::is_rejected_request(&e) => {
+                save_outbox(&queued[i + 1..])?;
+                ui::fail_error(&format!("Queued message from {} was rejected and dropped", queued_at), &e);
+                println!("  Message: {}", entry.display);
+            }
+            // Outages, bad keys, wrong URLs: keep everything for the next flush
             Err(e) => {
-                print!("\r");
-                println!("{} Error: {}", "✗".red(), e);
-                println!();
+                if api::client::is_retryable(&e) {
+                    println!("{} Chat service still unavailable: {}", "⚠".warning(), e);
+                } else {
+                    ui::fail_error("Stopped sending queued messages", &e);
+                    println!("  {}", "Fix the problem above, then run `pam chat --flush` again".dimmed());
+                }
+                println!("  {} queued message(s) are still waiting and unanswered", queued.len() - i);
+                return Ok(false);
             }
         }
     }
 
+    Ok(true)
+}
+
+fn history(limit: usize, quiet: bool) -> Result<()> {
+    ui::header("Chat History", quiet);
+
+    let mut sessions = load_sessions();
+    if sessions.is_empty() {
+        ui::empty(ui::Empty::ChatSessions, quiet);
+        return Ok(());
+    }
+
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.last_active));
+    for session in sessions.iter().take(limit) {
+        let label = match &session.name {
+            Some(name) => format!("{} ({})", name.bold(), session.id.dimmed()),
+            None => session.id.clone(),
+        };
+        println!("{} {}", "•".primary(), label);
+        println!(
+            "    {} messages, last active {} ({})",
+            session.messages,
+            session.last_active.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+            session.user_email.dimmed()
+        );
+    }
+
     Ok(())
 }
 
+/// Longest session id accepted from `--session-id`
+const MAX_SESSION_ID_LEN: usize = 128;
+
+/// Session ids end up in URLs and log lines, so keep them to a safe alphabet
+fn validate_session_id(id: &str) -> Result<()> {
+    if id.is_empty() || id.len() > MAX_SESSION_ID_LEN {
+        anyhow::bail!("must be 1-{} characters", MAX_SESSION_ID_LEN);
+    }
+    if let Some(c) = id.chars().find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))) {
+        anyhow::bail!("'{}' is not allowed (use letters, digits, '_', '-', '.')", c);
+    }
+    Ok(())
+}
+
+/// Makes generated session ids reproducible, for tests
+const SESSION_SEED_ENV: &str = "PAM_SESSION_SEED";
+
 fn generate_session_id() -> String {
-    format!(
-        "cli_{}_{:08x}",
-        chrono::Utc::now().format("%Y%m%d_%H%M%S"),
-        rand::random::<u32>()
-    )
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::sync::{Mutex, OnceLock};
+
+    // With a seed, successive ids in one run still differ but repeat across runs
+    static SEEDED: OnceLock<Option<(u64, Mutex<StdRng>)>> = OnceLock::new();
+    let seeded = SEEDED.get_or_init(|| {
+        let seed = std::env::var(SESSION_SEED_ENV).ok()?.parse().ok()?;
+        Some((seed, Mutex::new(StdRng::seed_from_u64(seed))))
+    });
+    match seeded {
+        // No timestamp, which would differ between runs
+        Some((seed, rng)) => {
+            let suffix = rng.lock().unwrap_or_else(|e| e.into_inner()).gen::<u32>();
+            format!("cli_seed{}_{:08x}", seed, suffix)
+        }
+        None => format!("cli_{}_{:08x}", chrono::Utc::now().format("%Y%m%d_%H%M%S"), rand::random::<u32>()),
+    }
 }
 
 fn print_help() {
     println!("\n{}", "Commands:".bold());
-    println!("  {}      - End the chat session", "quit, exit, q".cyan());
-    println!("  {}          - Start a new session", "clear".cyan());
-    println!("  {}       - Generate reflection from this session", "/reflect".cyan());
-    println!("  {}        - Show current session info", "/status".cyan());
-    println!("  {}           - Show this help", "help".cyan());
+    println!("  {}      - End the chat session", "quit, exit, q".primary());
+    println!("  {}          - Start a new session", "clear".primary());
+    println!("  {}       - Generate reflection from this session", "/reflect".primary());
+    println!("  {}        - Show current session info", "/status".primary());
+    println!("  {}  - Name this session", "/name <label>".primary());
+    println!("  {} - Override the system prompt (/system off to reset)", "/system <text>".primary());
+    println!("  {}          - Copy the last response to the clipboard", "/copy".primary());
+    println!("  {}         - Compose a multiline message in $EDITOR", "/multi".primary());
+    println!("  {}     - Resend your last message", "/retry, !!".primary());
+    println!("  {}           - Show this help", "help".primary());
     println!();
 }
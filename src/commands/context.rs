@@ -1,29 +1,98 @@
 //! Context bundle management commands
 
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::PathBuf;
+
 use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
 use colored::Colorize;
+use crate::theme::Themed;
+use serde::{Deserialize, Serialize};
 
+use crate::cache;
 use crate::config::Config;
+use crate::ui::{self, ListFormat};
 use crate::ContextAction;
 use crate::api;
 
-pub async fn handle(action: ContextAction, config: &Config, verbose: bool) -> Result<()> {
+pub async fn handle(action: ContextAction, user: Option<String>, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
+    let user = config.resolve_user_email(user);
+    let user = user.as_deref();
+
     match action {
-        ContextAction::Status { freshness } => status(freshness, config, verbose).await,
-        ContextAction::Refresh { force } => refresh(force, config, verbose).await,
-        ContextAction::Show { name, raw } => show(&name, raw, config, verbose).await,
-        ContextAction::List => list(config, verbose).await,
-        ContextAction::Stats => stats(config, verbose).await,
+        ContextAction::Status { freshness } => status(freshness, user, config, verbose, quiet).await,
+        ContextAction::Refresh { force, dry_run, watch_until_fresh, wait_timeout } => {
+            let wait = watch_until_fresh.then(|| std::time::Duration::from_secs(wait_timeout));
+            refresh(force, dry_run, wait, user, config, verbose, quiet).await
+        }
+        ContextAction::Show { name, watch: Some(secs), no_cache, section, .. } => {
+            watch_file(resolve_file_name(&name), secs, no_cache, section.as_deref(), user, config).await
+        }
+        ContextAction::Show { name, raw, no_cache, section, watch: None } => {
+            show(&name, raw, no_cache, section.as_deref(), user, config, verbose, quiet).await
+        }
+        ContextAction::List { json, format } => list(json, format, user, config, verbose, quiet).await,
+        ContextAction::Download { dir, overwrite, retries } => {
+            download(&dir, overwrite, retries, user, config, verbose, quiet).await
+        }
+        ContextAction::Stats { history } => stats(history, user, config, verbose, quiet).await,
     }
 }
 
-async fn status(freshness: bool, config: &Config, verbose: bool) -> Result<()> {
-    println!("{}", "Context Bundle Status".bold());
-    println!("{}", "─".repeat(40));
+/// Files younger than this (minutes) are fresh
+const FRESH_MINUTES: f64 = 30.0;
 
-    match api::client::get_context_status(&config.api_url).await {
-        Ok(status) => {
-            println!("{} Context bundle: {}", "•".green(), "Available".green());
+/// Files older than this (minutes) are stale
+const STALE_MINUTES: f64 = 60.0;
+
+/// Cache file name for the last context status, served when offline
+const CONTEXT_STATUS_CACHE: &str = "context_status";
+
+/// [`CONTEXT_STATUS_CACHE`], kept separately per user
+fn status_cache_name(user: Option<&str>) -> String {
+    match user {
+        Some(user) => format!("{}_{}", CONTEXT_STATUS_CACHE, user.replace(['/', '\\'], "_")),
+        None => CONTEXT_STATUS_CACHE.to_string(),
+    }
+}
+
+/// File age like "12m old"; negative ages (clock skew) show as "just now"
+fn age_label(age_minutes: f64) -> String {
+    if age_minutes < 0.0 {
+        ui::warn_clock_skew();
+    }
+    if age_minutes < 1.0 {
+        "just now".to_string()
+    } else {
+        format!("{:.0}m old", age_minutes)
+    }
+}
+
+/// Report a failed context call; when the API answered but the context
+/// subsystem didn't, point at the likely cause instead of the whole API
+fn fail_context(what: &str, e: &anyhow::Error) {
+    ui::fail_error(what, e);
+    if api::client::is_context_outage(e) && !ui::json_errors() {
+        println!(
+            "{} {}",
+            "→".dimmed(),
+            "The API is up but its context subsystem isn't; check the backend's GCS credentials \
+             (memory, skills, and chat still work). Run `pam health --deep` for details."
+                .dimmed()
+        );
+    }
+}
+
+async fn status(freshness: bool, user: Option<&str>, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
+    ui::header("Context Bundle Status", quiet);
+
+    let fetch = api::client::get_context_status(&config.api_url, user);
+    match cache::fetch_or_cached(&status_cache_name(user), config, fetch).await {
+        Ok(fetched) => {
+            fetched.announce();
+            let status = fetched.data;
+            println!("{} Context bundle: {}", "•".success(), "Available".success());
             println!("  Files:  {}", status.file_count);
             println!("  Size:   {:.2} KB", status.total_size_kb);
             println!("  Tokens: ~{}", status.estimated_tokens);
@@ -31,140 +100,779 @@ async fn status(freshness: bool, config: &Config, verbose: bool) -> Result<()> {
             if freshness || verbose {
                 println!("\n{}", "File Freshness:".bold());
                 for file in &status.files {
-                    let freshness_icon = if file.age_minutes < 30.0 {
+                    let freshness_icon = if file.age_minutes < FRESH_MINUTES {
                         "🟢".to_string()
-                    } else if file.age_minutes < 60.0 {
+                    } else if file.age_minutes < STALE_MINUTES {
                         "🟡".to_string()
                     } else {
                         "🔴".to_string()
                     };
 
                     println!(
-                        "  {} {} ({:.0}m old, {:.1} KB)",
+                        "  {} {} ({}, {:.1} KB)",
                         freshness_icon,
                         file.name,
-                        file.age_minutes,
+                        age_label(file.age_minutes),
                         file.size_kb
                     );
                 }
             }
         }
         Err(e) => {
-            println!("{} Context status failed: {}", "✗".red(), e);
+            fail_context("Context status failed", &e);
         }
     }
 
     Ok(())
 }
 
-async fn refresh(force: bool, config: &Config, verbose: bool) -> Result<()> {
+async fn refresh(
+    force: bool,
+    dry_run: bool,
+    wait: Option<std::time::Duration>,
+    user: Option<&str>,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    if dry_run {
+        return refresh_dry_run(force, user, config, quiet).await;
+    }
+
     if verbose {
         println!("Refreshing context bundle (force={})", force);
     }
 
-    println!("Refreshing context from GCS...");
+    let spinner = ui::spinner("Refreshing context from GCS...", quiet);
+    let outcome = api::client::refresh_context(&config.api_url, user, force).await;
+    spinner.finish_and_clear();
 
-    match api::client::refresh_context(&config.api_url, force).await {
+    match outcome {
         Ok(result) => {
-            println!("{} Context refreshed", "✓".green());
+            println!("{} Context refreshed", "✓".success());
             println!("  Files loaded: {}", result.files_loaded);
             println!("  Total size:   {:.2} KB", result.total_size_kb);
+            if let Some(timeout) = wait {
+                wait_until_fresh(timeout, user, config, quiet).await?;
+            }
         }
         Err(e) => {
-            println!("{} Refresh failed: {}", "✗".red(), e);
+            fail_context("Refresh failed", &e);
         }
     }
 
     Ok(())
 }
 
-async fn show(name: &str, raw: bool, config: &Config, _verbose: bool) -> Result<()> {
-    // Map friendly names to actual file names
-    let filename = match name.to_lowercase().as_str() {
-        "github" | "git" => "github_ai_garage.md",
-        "jira" => "jira_summary.md",
-        "daily" | "ambition" | "daily-ambition" => "daily_ambitions_summary.md",
-        "strategic" => "strategic_context_30min.md",
-        "tactical" => "tactical_context_10min.md",
-        "operational" => "operational_context_5min.md",
-        "database" | "db" => "database_summary.md",
-        _ => name,
+/// Seconds between status polls while waiting for a refresh to land
+const FRESH_POLL_SECS: u64 = 5;
+
+/// Poll the bundle status until every file is younger than [`FRESH_MINUTES`],
+/// printing a dot per poll; reports a failure if `timeout` passes first.
+async fn wait_until_fresh(
+    timeout: std::time::Duration,
+    user: Option<&str>,
+    config: &Config,
+    quiet: bool,
+) -> Result<()> {
+    let started = std::time::Instant::now();
+    if !quiet {
+        print!("Waiting for fresh context");
+    }
+
+    loop {
+        // Errors are treated as "not fresh yet": the backend may be busy reloading
+        let stale = api::client::get_context_status(&config.api_url, user)
+            .await
+            .map(|status| status.files.iter().filter(|f| f.age_minutes >= FRESH_MINUTES).count())
+            .ok();
+
+        if stale == Some(0) {
+            if !quiet {
+                println!();
+            }
+            println!("{} All files fresh after {}s", "✓".success(), started.elapsed().as_secs());
+            return Ok(());
+        }
+        if started.elapsed() >= timeout {
+            if !quiet {
+                println!();
+            }
+            match stale {
+                Some(n) => ui::fail(format_args!("{} files still stale after {}s", n, timeout.as_secs())),
+                None => ui::fail(format_args!("Context status unavailable after {}s", timeout.as_secs())),
+            }
+            return Ok(());
+        }
+
+        if !quiet {
+            print!(".");
+            std::io::stdout().flush()?;
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(FRESH_POLL_SECS)).await;
+    }
+}
+
+/// Refresh the bundle first when any file is stale, for `--refresh-before` on
+/// commands that read context implicitly (chat, reflect).
+///
+/// Failures only warn: the command goes on with the bundle as it is. Notes go
+/// to stderr when `to_stderr` is set, so machine-readable stdout stays clean.
+pub async fn refresh_if_stale(config: &Config, verbose: bool, quiet: bool, to_stderr: bool) {
+    let note = |line: String| if to_stderr { eprintln!("{}", line) } else { println!("{}", line) };
+
+    if config.offline {
+        note(format!("{} Skipping context refresh: --offline is set", "⚠".warning()));
+        return;
+    }
+
+    let user = config.resolve_user_email(None);
+    let oldest = match api::client::get_context_status(&config.api_url, user.as_deref()).await {
+        Ok(status) => status.files.iter().map(|f| f.age_minutes).fold(0.0, f64::max),
+        Err(e) => {
+            note(format!("{} Could not check context freshness: {}", "⚠".warning(), e));
+            return;
+        }
     };
+    if oldest < STALE_MINUTES {
+        if verbose {
+            note(format!("Context is fresh (oldest file {:.0}m old), not refreshing", oldest));
+        }
+        return;
+    }
+
+    let spinner = ui::spinner("Refreshing stale context...", quiet || to_stderr);
+    let outcome = api::client::refresh_context(&config.api_url, user.as_deref(), false).await;
+    spinner.finish_and_clear();
+
+    match outcome {
+        Ok(result) if !quiet => note(format!(
+            "{} Refreshed context ({} files; oldest was {:.0}m old)",
+            "↻".primary(),
+            result.files_loaded,
+            oldest
+        )),
+        Ok(_) => {}
+        Err(e) => note(format!("{} Context refresh failed, using the current bundle: {}", "⚠".warning(), e)),
+    }
+}
+
+/// Report which files a refresh would reload, based on their age
+async fn refresh_dry_run(force: bool, user: Option<&str>, config: &Config, quiet: bool) -> Result<()> {
+    ui::header("Context Refresh (dry run)", quiet);
+
+    let status = match api::client::get_context_status(&config.api_url, user).await {
+        Ok(status) => status,
+        Err(e) => {
+            fail_context("Context status failed", &e);
+            return Ok(());
+        }
+    };
+
+    let (mut due, fresh): (Vec<_>, Vec<_>) = status
+        .files
+        .iter()
+        .partition(|f| force || f.age_minutes >= FRESH_MINUTES);
+    due.sort_by(|a, b| b.age_minutes.total_cmp(&a.age_minutes));
+
+    if due.is_empty() {
+        println!("{} All {} files are fresh; nothing to refresh", "✓".success(), fresh.len());
+        return Ok(());
+    }
+
+    println!("{}", "Would refresh:".bold());
+    for file in &due {
+        let age = age_label(file.age_minutes);
+        let age = if file.age_minutes >= STALE_MINUTES {
+            age.error()
+        } else if file.age_minutes >= FRESH_MINUTES {
+            age.warning()
+        } else {
+            age.success()
+        };
+        println!("  • {} ({}, {:.1} KB)", file.name, age, file.size_kb);
+    }
+
+    let due_kb: f64 = due.iter().map(|f| f.size_kb).sum();
+    println!(
+        "\n{} {} of {} files ({:.1} KB) would be refreshed{}",
+        "•".primary(),
+        due.len(),
+        status.files.len(),
+        due_kb,
+        if force { " (--force)" } else { "" }
+    );
+
+    Ok(())
+}
 
-    match api::client::get_context_file(&config.api_url, filename).await {
-        Ok(content) => {
+#[allow(clippy::too_many_arguments)]
+async fn show(
+    name: &str,
+    raw: bool,
+    no_cache: bool,
+    section: Option<&str>,
+    user: Option<&str>,
+    config: &Config,
+    _verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    let filename = resolve_file_name(name);
+
+    let outcome = cache::with_fallback(
+        config,
+        api::client::get_context_file(&config.api_url, filename, user, no_cache),
+        || api::client::cached_context_file(&config.api_url, filename, user),
+    )
+    .await;
+
+    match outcome {
+        Ok(fetched) => {
+            fetched.announce();
+            let content = match section {
+                Some(heading) => match extract_section(&fetched.data, heading) {
+                    Some(section) => section,
+                    None => {
+                        ui::fail(format_args!("No section matching '{}' in {}", heading, filename));
+                        print_headings(&fetched.data);
+                        return Ok(());
+                    }
+                },
+                None => fetched.data,
+            };
             if raw {
                 println!("{}", content);
             } else {
-                println!("{}", format!("Context: {}", filename).bold());
-                println!("{}", "─".repeat(40));
+                ui::header(&format!("Context: {}", filename), quiet);
                 println!("{}", content);
             }
         }
         Err(e) => {
-            println!("{} Failed to load context file: {}", "✗".red(), e);
+            fail_context("Failed to load context file", &e);
         }
     }
 
     Ok(())
 }
 
-async fn list(config: &Config, _verbose: bool) -> Result<()> {
-    println!("{}", "Context Files".bold());
-    println!("{}", "─".repeat(40));
+/// Map friendly names to actual file names
+fn resolve_file_name(name: &str) -> &str {
+    match name.to_lowercase().as_str() {
+        "github" | "git" => "github_ai_garage.md",
+        "jira" => "jira_summary.md",
+        "daily" | "ambition" | "daily-ambition" => "daily_ambitions_summary.md",
+        "strategic" => "strategic_context_30min.md",
+        "tactical" => "tactical_context_10min.md",
+        "operational" => "operational_context_5min.md",
+        "database" | "db" => "database_summary.md",
+        _ => name,
+    }
+}
 
-    match api::client::list_context_files(&config.api_url).await {
-        Ok(files) => {
-            println!("\n{}", "Real-Time Layers:".cyan());
-            for f in files.iter().filter(|f| f.name.contains("context_")) {
-                println!("  • {} ({:.1} KB)", f.name, f.size_kb);
+/// Re-fetch one file on an interval, redrawing only when its content changes.
+///
+/// Polls revalidate with the cached ETag, so an unchanged file costs a `304`.
+async fn watch_file(
+    filename: &str,
+    secs: u64,
+    no_cache: bool,
+    section: Option<&str>,
+    user: Option<&str>,
+    config: &Config,
+) -> Result<()> {
+    ui::install_interrupt_handler();
+
+    let interval = std::time::Duration::from_secs(secs.max(1));
+    let mut shown: Option<String> = None;
+    loop {
+        match api::client::get_context_file(&config.api_url, filename, user, no_cache).await {
+            Ok(content) => {
+                let content = match section {
+                    Some(heading) => extract_section(&content, heading)
+                        .unwrap_or_else(|| format!("(no section matching '{}' yet)", heading)),
+                    None => content,
+                };
+                if shown.as_ref() != Some(&content) {
+                    print!("\x1B[2J\x1B[H");
+                    println!(
+                        "{}",
+                        format!(
+                            "Every {}s: {} - changed {} (Ctrl-C to stop)",
+                            interval.as_secs(),
+                            filename,
+                            chrono::Local::now().format("%H:%M:%S")
+                        )
+                        .dimmed()
+                    );
+                    println!("\n{}", content);
+                    shown = Some(content);
+                }
             }
+            Err(e) => println!("{} Failed to refresh {}: {} (retrying)", "⚠".warning(), filename, e),
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
 
-            println!("\n{}", "Project Data:".cyan());
-            for f in files.iter().filter(|f| f.name.contains("summary") || f.name.contains("activity")) {
-                println!("  • {} ({:.1} KB)", f.name, f.size_kb);
+/// Markdown headings as `(line index, level, text)`, skipping fenced code blocks
+fn headings(content: &str) -> Vec<(usize, usize, &str)> {
+    let mut in_fence = false;
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+                return None;
+            }
+            if in_fence {
+                return None;
             }
+            let level = trimmed.chars().take_while(|&c| c == '#').count();
+            let text = trimmed[level..].strip_prefix(' ')?;
+            (1..=6).contains(&level).then(|| (i, level, text.trim().trim_end_matches('#').trim_end()))
+        })
+        .collect()
+}
+
+/// The first section whose heading starts with `query` (case-insensitive),
+/// including its sub-sections, up to the next heading at the same or a higher level
+fn extract_section(content: &str, query: &str) -> Option<String> {
+    let query = query.trim().to_lowercase();
+    let headings = headings(content);
+    let (pos, &(start, level, _)) = headings
+        .iter()
+        .enumerate()
+        .find(|(_, (_, _, text))| text.to_lowercase().starts_with(&query))?;
+    let end = headings[pos + 1..]
+        .iter()
+        .find(|&&(_, l, _)| l <= level)
+        .map(|&(line, _, _)| line)
+        .unwrap_or(usize::MAX);
+
+    let lines: Vec<&str> = content.lines().take(end).skip(start).collect();
+    Some(lines.join("\n").trim_end().to_string())
+}
+
+/// List a file's headings, indented by level, so the user can pick a `--section`
+fn print_headings(content: &str) {
+    let headings = headings(content);
+    if headings.is_empty() {
+        println!("This file has no markdown headings.");
+        return;
+    }
+    println!("\n{}", "Available sections:".bold());
+    for (_, level, text) in headings {
+        println!("{}• {}", "  ".repeat(level), text);
+    }
+}
+
+/// Category a context file belongs to, matching the `context stats` breakdown
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+enum ContextCategory {
+    Realtime,
+    Projects,
+    Team,
+    Activity,
+    Other,
+}
+
+impl ContextCategory {
+    const ALL: [ContextCategory; 5] = [
+        ContextCategory::Realtime,
+        ContextCategory::Projects,
+        ContextCategory::Team,
+        ContextCategory::Activity,
+        ContextCategory::Other,
+    ];
+
+    /// Classify a file by its bundle path
+    fn of(name: &str) -> ContextCategory {
+        if name.contains("context_") {
+            ContextCategory::Realtime
+        } else if name.contains("activity") {
+            ContextCategory::Activity
+        } else if name.contains("summary") {
+            ContextCategory::Projects
+        } else if name.contains("person") || name.contains("people/") {
+            ContextCategory::Team
+        } else {
+            ContextCategory::Other
+        }
+    }
+
+    /// Lowercase name, as used in JSON output
+    fn name(self) -> &'static str {
+        match self {
+            ContextCategory::Realtime => "realtime",
+            ContextCategory::Projects => "projects",
+            ContextCategory::Team => "team",
+            ContextCategory::Activity => "activity",
+            ContextCategory::Other => "other",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            ContextCategory::Realtime => "Real-Time Layers:",
+            ContextCategory::Projects => "Project Data:",
+            ContextCategory::Team => "Team Profiles:",
+            ContextCategory::Activity => "Activity:",
+            ContextCategory::Other => "Other:",
+        }
+    }
+}
+
+async fn list(
+    json: bool,
+    format: ListFormat,
+    user: Option<&str>,
+    config: &Config,
+    _verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    let plain = json || matches!(format, ListFormat::Ndjson | ListFormat::Tsv);
+    if !plain {
+        ui::header("Context Files", quiet);
+    }
+
+    let files = match api::client::list_context_files(&config.api_url, user).await {
+        Ok(files) => files,
+        Err(e) => {
+            fail_context("Failed to list context files", &e);
+            return Ok(());
+        }
+    };
+
+    let mut groups: BTreeMap<ContextCategory, Vec<&api::client::ContextFile>> = BTreeMap::new();
+    for f in &files {
+        groups.entry(ContextCategory::of(&f.name)).or_default().push(f);
+    }
 
-            println!("\n{}", "Team Profiles:".cyan());
-            for f in files.iter().filter(|f| f.name.contains("person") || f.name.contains("people/")) {
-                println!("  • {} ({:.1} KB)", f.name, f.size_kb);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&groups)?);
+        return Ok(());
+    }
+
+    let rows = ContextCategory::ALL
+        .into_iter()
+        .flat_map(|category| groups.get(&category).into_iter().flatten().map(move |f| (category, *f)));
+    match format {
+        ListFormat::Ndjson => {
+            for (category, f) in rows {
+                let line = serde_json::json!({
+                    "category": category,
+                    "name": f.name,
+                    "size_kb": f.size_kb,
+                    "age_minutes": f.age_minutes,
+                });
+                println!("{}", line);
+            }
+            return Ok(());
+        }
+        ListFormat::Tsv => {
+            println!("category\tname\tsize_kb\tage_minutes");
+            for (category, f) in rows {
+                let row = [
+                    category.name().to_string(),
+                    f.name.clone(),
+                    format!("{:.1}", f.size_kb),
+                    format!("{:.0}", f.age_minutes),
+                ];
+                println!("{}", ui::tsv_row(&row));
+            }
+            return Ok(());
+        }
+        ListFormat::Table => {
+            let mut table = ui::table(&["Category", "File", "Size", "Age"]);
+            for (category, f) in rows {
+                ui::table_row(
+                    &mut table,
+                    vec![
+                        category.name().to_string(),
+                        f.name.clone(),
+                        format!("{:.1} KB", f.size_kb),
+                        age_label(f.age_minutes),
+                    ],
+                );
             }
+            println!("{}", table);
+            println!("\n{} {} files total", "✓".success(), files.len());
+            return Ok(());
+        }
+        ListFormat::List => {}
+    }
 
-            println!("\n{} {} files total", "✓".green(), files.len());
+    for category in ContextCategory::ALL {
+        let Some(group) = groups.get(&category) else { continue };
+        println!("\n{}", category.label().primary());
+        for f in group {
+            println!("  • {} ({:.1} KB)", f.name, f.size_kb);
         }
+    }
+
+    println!("\n{} {} files total", "✓".success(), files.len());
+
+    Ok(())
+}
+
+/// Outcome of downloading one context file
+enum Download {
+    Saved,
+    Skipped,
+    Failed(anyhow::Error),
+}
+
+async fn download(
+    dir: &std::path::Path,
+    overwrite: bool,
+    retries: u32,
+    user: Option<&str>,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    use futures::stream::{self, StreamExt};
+
+    ui::header("Downloading Context Files", quiet);
+
+    let files = match api::client::list_context_files(&config.api_url, user).await {
+        Ok(files) => files,
         Err(e) => {
-            println!("{} Failed to list context files: {}", "✗".red(), e);
+            fail_context("Failed to list context files", &e);
+            return Ok(());
         }
+    };
+    std::fs::create_dir_all(dir)?;
+
+    let outcomes: Vec<(String, Download)> = stream::iter(&files)
+        .map(|f| async move {
+            let outcome = download_file(&f.name, dir, overwrite, retries, user, config).await;
+            match &outcome {
+                Download::Saved if !quiet => println!("{} {}", "✓".success(), f.name),
+                Download::Skipped if verbose => println!("{} {} (already present)", "•".dimmed(), f.name),
+                Download::Failed(e) => println!("{} {}: {}", "✗".error(), f.name, e),
+                _ => {}
+            }
+            (f.name.clone(), outcome)
+        })
+        .buffer_unordered(config.concurrency())
+        .collect()
+        .await;
+
+    let saved = outcomes.iter().filter(|(_, o)| matches!(o, Download::Saved)).count();
+    let skipped = outcomes.iter().filter(|(_, o)| matches!(o, Download::Skipped)).count();
+    let mut failed: Vec<&str> = outcomes
+        .iter()
+        .filter(|(_, o)| matches!(o, Download::Failed(_)))
+        .map(|(name, _)| name.as_str())
+        .collect();
+    failed.sort_unstable();
+
+    println!();
+    println!(
+        "{} {} downloaded, {} already present, {} failed ({})",
+        "✓".success(),
+        saved,
+        skipped,
+        failed.len(),
+        dir.display()
+    );
+    if !failed.is_empty() {
+        ui::fail(format_args!("Failed: {}", failed.join(", ")));
+        println!("  Re-run {} to fetch just the missing files", "pam context download".primary());
     }
 
     Ok(())
 }
 
-async fn stats(config: &Config, _verbose: bool) -> Result<()> {
-    println!("{}", "Context Bundle Statistics".bold());
-    println!("{}", "─".repeat(40));
+/// Download one file into `dir`, retrying network errors.
+///
+/// Files are written to a `.part` file and renamed into place, so an
+/// interrupted run never leaves a truncated file that a re-run would skip.
+async fn download_file(
+    name: &str,
+    dir: &std::path::Path,
+    overwrite: bool,
+    retries: u32,
+    user: Option<&str>,
+    config: &Config,
+) -> Download {
+    let Some(path) = local_path(dir, name) else {
+        return Download::Failed(anyhow::anyhow!("invalid file name"));
+    };
+    if path.exists() && !overwrite {
+        return Download::Skipped;
+    }
+
+    let mut attempt = 0;
+    let body = loop {
+        match api::client::get_context_file(&config.api_url, name, user, true).await {
+            Ok(body) => break body,
+            Err(e) if attempt < retries && api::client::is_network_error(&e) => {
+                attempt += 1;
+                tracing::debug!("Retrying {} ({}/{}): {}", name, attempt, retries, e);
+                tokio::time::sleep(std::time::Duration::from_secs(attempt.into())).await;
+            }
+            Err(e) => return Download::Failed(e),
+        }
+    };
 
-    match api::client::get_context_stats(&config.api_url).await {
+    let mut part = path.clone().into_os_string();
+    part.push(".part");
+    let written = match path.parent() {
+        Some(parent) => std::fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+    .and_then(|_| std::fs::write(&part, body))
+    .and_then(|_| std::fs::rename(&part, &path));
+    match written {
+        Ok(()) => Download::Saved,
+        Err(e) => Download::Failed(e.into()),
+    }
+}
+
+/// Where server file `name` (e.g. `people/x.md`) goes under `dir`.
+///
+/// Names come from the server, so anything that could escape `dir` (`..`,
+/// absolute paths, drive prefixes) is rejected.
+fn local_path(dir: &std::path::Path, name: &str) -> Option<PathBuf> {
+    use std::path::Component;
+
+    let mut path = dir.to_path_buf();
+    let mut parts = 0;
+    for component in std::path::Path::new(name).components() {
+        match component {
+            Component::Normal(part) => {
+                path.push(part);
+                parts += 1;
+            }
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+    }
+    (parts > 0).then_some(path)
+}
+
+async fn stats(history: bool, user: Option<&str>, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
+    ui::header("Context Bundle Statistics", quiet);
+
+    match api::client::get_context_stats(&config.api_url, user).await {
         Ok(stats) => {
-            println!("\n{}", "Size Breakdown:".cyan());
+            if let Err(e) = record_stats(&stats) {
+                if verbose {
+                    println!("Could not record stats history: {}", e);
+                }
+            }
+
+            println!("\n{}", "Size Breakdown:".primary());
             println!("  Total Size:      {:.2} KB", stats.total_size_kb);
             println!("  Estimated Tokens: ~{}", stats.estimated_tokens);
 
-            println!("\n{}", "By Category:".cyan());
+            println!("\n{}", "By Category:".primary());
             println!("  Real-Time:   {:.1} KB ({:.0}%)", stats.realtime_kb, stats.realtime_pct);
             println!("  Projects:    {:.1} KB ({:.0}%)", stats.projects_kb, stats.projects_pct);
             println!("  Team:        {:.1} KB ({:.0}%)", stats.team_kb, stats.team_pct);
             println!("  Activity:    {:.1} KB ({:.0}%)", stats.activity_kb, stats.activity_pct);
 
-            println!("\n{}", "Team Members:".cyan());
+            println!("\n{}", "Team Members:".primary());
             for member in &stats.team_members {
                 println!("  • {}", member);
             }
         }
         Err(e) => {
-            println!("{} Failed to get context stats: {}", "✗".red(), e);
+            fail_context("Failed to get context stats", &e);
         }
     }
 
+    if history {
+        print_history()?;
+    }
+
+    Ok(())
+}
+
+/// Number of past `stats` runs shown by `--history`
+const HISTORY_RUNS: usize = 30;
+
+const SPARK_CHARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One `context stats` run, appended to the local history log
+#[derive(Serialize, Deserialize)]
+struct StatsRecord {
+    recorded_at: DateTime<Utc>,
+    total_size_kb: f64,
+    estimated_tokens: i64,
+}
+
+fn history_path() -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join("context_stats_history.jsonl"))
+}
+
+fn record_stats(stats: &api::client::ContextStats) -> Result<()> {
+    let record = StatsRecord {
+        recorded_at: Utc::now(),
+        total_size_kb: stats.total_size_kb,
+        estimated_tokens: stats.estimated_tokens,
+    };
+
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_path()?)?;
+    writeln!(file, "{}", serde_json::to_string(&record)?)?;
+    Ok(())
+}
+
+fn print_history() -> Result<()> {
+    let content = std::fs::read_to_string(history_path()?).unwrap_or_default();
+    let records: Vec<StatsRecord> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    let recent = &records[records.len().saturating_sub(HISTORY_RUNS)..];
+
+    println!("\n{}", "Size History:".primary());
+    let (Some(first), Some(last)) = (recent.first(), recent.last()) else {
+        ui::empty(ui::Empty::ContextHistory, false);
+        return Ok(());
+    };
+
+    let sizes: Vec<f64> = recent.iter().map(|r| r.total_size_kb).collect();
+    let min = sizes.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = sizes.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let spark: String = sizes
+        .iter()
+        .map(|&size| {
+            let level = if max > min {
+                ((size - min) / (max - min) * (SPARK_CHARS.len() - 1) as f64).round() as usize
+            } else {
+                SPARK_CHARS.len() / 2
+            };
+            SPARK_CHARS[level]
+        })
+        .collect();
+
+    println!("  {}", spark);
+    println!(
+        "  {} runs, {} → {}",
+        recent.len(),
+        first.recorded_at.with_timezone(&Local).format("%Y-%m-%d %H:%M"),
+        last.recorded_at.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+    );
+    println!("  Range:  {:.1} KB – {:.1} KB", min, max);
+
+    let delta = last.total_size_kb - first.total_size_kb;
+    let pct = if first.total_size_kb > 0.0 { delta / first.total_size_kb * 100.0 } else { 0.0 };
+    let change = format!("{:+.1} KB ({:+.0}%)", delta, pct);
+    println!(
+        "  Change: {}  (~{} → ~{} tokens)",
+        if delta > 0.0 { change.warning() } else { change.success() },
+        first.estimated_tokens,
+        last.estimated_tokens
+    );
+
     Ok(())
 }
@@ -0,0 +1,306 @@
+//! Live dashboard - full-screen overview of memory, context, skills, and health
+
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::api::client::{ContextStatus, Health, MemoryStatus, SkillLogEntry};
+use crate::config::Config;
+use crate::ui;
+use crate::api;
+
+/// Number of skill log entries shown in the dashboard
+const SKILL_LOG_LIMIT: usize = 20;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Pane {
+    Memory,
+    Context,
+    Skills,
+    Health,
+}
+
+impl Pane {
+    const ALL: [Pane; 4] = [Pane::Memory, Pane::Context, Pane::Skills, Pane::Health];
+
+    fn title(self) -> &'static str {
+        match self {
+            Pane::Memory => " Memory ",
+            Pane::Context => " Context ",
+            Pane::Skills => " Skill Log ",
+            Pane::Health => " Health ",
+        }
+    }
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|p| *p == self).unwrap_or(0)
+    }
+
+    fn next(self) -> Pane {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn prev(self) -> Pane {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// One round of data fetched from the API
+struct Snapshot {
+    memory: Result<MemoryStatus, String>,
+    context: Result<ContextStatus, String>,
+    skill_log: Result<Vec<SkillLogEntry>, String>,
+    health: Result<Health, String>,
+    fetched_at: DateTime<Local>,
+}
+
+struct Dashboard {
+    focus: Pane,
+    scroll: [u16; 4],
+    snapshot: Snapshot,
+}
+
+pub async fn handle(interval: u64, config: &Config, _verbose: bool) -> Result<()> {
+    let snapshot = fetch_snapshot(config).await;
+
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, snapshot, interval, config).await;
+    ratatui::restore();
+
+    // Raw mode turns Ctrl-C into a key press, so exit with the usual code ourselves
+    if let Ok(Exit::Interrupted) = result {
+        std::process::exit(ui::INTERRUPTED_EXIT_CODE);
+    }
+
+    result.map(|_| ())
+}
+
+/// How the user left the dashboard
+enum Exit {
+    Quit,
+    Interrupted,
+}
+
+async fn run(
+    terminal: &mut DefaultTerminal,
+    snapshot: Snapshot,
+    interval: u64,
+    config: &Config,
+) -> Result<Exit> {
+    let refresh_every = Duration::from_secs(interval.max(1));
+    let mut last_fetch = Instant::now();
+    let mut dashboard = Dashboard {
+        focus: Pane::Memory,
+        scroll: [0; 4],
+        snapshot,
+    };
+
+    loop {
+        terminal.draw(|frame| draw(frame, &dashboard))?;
+
+        if event::poll(Duration::from_millis(200))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                let focused = dashboard.focus.index();
+                match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Ok(Exit::Interrupted);
+                    }
+                    KeyCode::Char('q') | KeyCode::Esc => break,
+                    KeyCode::Tab | KeyCode::Right => dashboard.focus = dashboard.focus.next(),
+                    KeyCode::BackTab | KeyCode::Left => dashboard.focus = dashboard.focus.prev(),
+                    KeyCode::Char(c @ '1'..='4') => {
+                        dashboard.focus = Pane::ALL[c as usize - '1' as usize];
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        dashboard.scroll[focused] = dashboard.scroll[focused].saturating_add(1);
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        dashboard.scroll[focused] = dashboard.scroll[focused].saturating_sub(1);
+                    }
+                    KeyCode::Char('r') => {
+                        dashboard.snapshot = fetch_snapshot(config).await;
+                        last_fetch = Instant::now();
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if last_fetch.elapsed() >= refresh_every {
+            dashboard.snapshot = fetch_snapshot(config).await;
+            last_fetch = Instant::now();
+        }
+    }
+
+    Ok(Exit::Quit)
+}
+
+async fn fetch_snapshot(config: &Config) -> Snapshot {
+    let user = config.resolve_user_email(None);
+    let (memory, context, skill_log, health) = tokio::join!(
+        api::client::get_memory_status(&config.api_url),
+        api::client::get_context_status(&config.api_url, user.as_deref()),
+        api::client::get_skill_log(&config.api_url, None, SKILL_LOG_LIMIT, false, None),
+        api::client::health_check(&config.api_url),
+    );
+
+    Snapshot {
+        memory: memory.map_err(|e| e.to_string()),
+        context: context.map_err(|e| e.to_string()),
+        skill_log: skill_log.map(|page| page.items).map_err(|e| e.to_string()),
+        health: health.map_err(|e| e.to_string()),
+        fetched_at: Local::now(),
+    }
+}
+
+fn draw(frame: &mut Frame, dashboard: &Dashboard) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let [top, bottom] = Layout::vertical([Constraint::Percentage(50); 2]).areas(body);
+    let [top_left, top_right] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(top);
+    let [bottom_left, bottom_right] = Layout::horizontal([Constraint::Percentage(50); 2]).areas(bottom);
+
+    let snapshot = &dashboard.snapshot;
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("PAM Dashboard", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!("  updated {}", snapshot.fetched_at.format("%H:%M:%S"))),
+        ])),
+        header,
+    );
+    frame.render_widget(
+        Paragraph::new("q quit  tab/1-4 focus  ↑↓ scroll  r refresh")
+            .style(Style::default().fg(Color::DarkGray)),
+        footer,
+    );
+
+    let panes = [
+        (Pane::Memory, top_left, memory_lines(snapshot)),
+        (Pane::Context, top_right, context_lines(snapshot)),
+        (Pane::Skills, bottom_left, skill_log_lines(snapshot)),
+        (Pane::Health, bottom_right, health_lines(snapshot)),
+    ];
+
+    for (pane, area, lines) in panes {
+        let border_style = if pane == dashboard.focus {
+            Style::default().fg(Color::Cyan)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        let block = Block::default()
+            .title(pane.title())
+            .borders(Borders::ALL)
+            .border_style(border_style);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(block)
+                .scroll((dashboard.scroll[pane.index()], 0)),
+            area,
+        );
+    }
+}
+
+fn error_line(e: &str) -> Line<'static> {
+    Line::styled(format!("✗ {}", e), Style::default().fg(Color::Red))
+}
+
+fn memory_lines(snapshot: &Snapshot) -> Vec<Line<'static>> {
+    match &snapshot.memory {
+        Ok(stats) => {
+            let mut lines = vec![
+                Line::from(format!("Total memories:    {}", ui::count_or_unknown(stats.total_memories))),
+                Line::from(format!("Total sessions:    {}", ui::count_or_unknown(stats.total_sessions))),
+                Line::from(format!("Total reflections: {}", ui::count_or_unknown(stats.total_reflections))),
+                Line::from(""),
+            ];
+            lines.extend(
+                stats
+                    .tables
+                    .iter()
+                    .map(|t| Line::from(format!("• {}: {} rows", t.name, t.row_count))),
+            );
+            lines
+        }
+        Err(e) => vec![error_line(e)],
+    }
+}
+
+fn context_lines(snapshot: &Snapshot) -> Vec<Line<'static>> {
+    match &snapshot.context {
+        Ok(status) => {
+            let mut lines = vec![
+                Line::from(format!(
+                    "{} files, {:.2} KB, ~{} tokens",
+                    status.file_count, status.total_size_kb, status.estimated_tokens
+                )),
+                Line::from(""),
+            ];
+            lines.extend(status.files.iter().map(|f| {
+                let color = if f.age_minutes < 30.0 {
+                    Color::Green
+                } else if f.age_minutes < 60.0 {
+                    Color::Yellow
+                } else {
+                    Color::Red
+                };
+                Line::from(vec![
+                    Span::styled("● ", Style::default().fg(color)),
+                    Span::raw(format!("{} ({:.0}m old)", f.name, f.age_minutes.max(0.0))),
+                ])
+            }));
+            lines
+        }
+        Err(e) => vec![error_line(e)],
+    }
+}
+
+fn skill_log_lines(snapshot: &Snapshot) -> Vec<Line<'static>> {
+    match &snapshot.skill_log {
+        Ok(entries) if entries.is_empty() => vec![Line::from("No log entries found.")],
+        Ok(entries) => entries
+            .iter()
+            .map(|entry| {
+                let (icon, color) = if entry.success {
+                    ("✓ ", Color::Green)
+                } else {
+                    ("✗ ", Color::Red)
+                };
+                Line::from(vec![
+                    Span::styled(icon, Style::default().fg(color)),
+                    Span::styled(entry.skill_key.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                    Span::raw(format!(" {}ms - {}", entry.duration_ms, entry.created_at)),
+                ])
+            })
+            .collect(),
+        Err(e) => vec![error_line(e)],
+    }
+}
+
+fn health_lines(snapshot: &Snapshot) -> Vec<Line<'static>> {
+    match &snapshot.health {
+        Ok(health) => {
+            let color = if health.status == "Healthy" { Color::Green } else { Color::Yellow };
+            let mut lines = vec![Line::styled(format!("API: {}", health.status), Style::default().fg(color))];
+            if let Some(version) = &health.version {
+                lines.push(Line::from(format!("Version: {}", version)));
+            }
+            lines
+        }
+        Err(e) => vec![error_line(e)],
+    }
+}
@@ -1,87 +1,476 @@
 //! Memory management commands
 
+use std::io::IsTerminal;
+
 use anyhow::Result;
 use colored::Colorize;
+use crate::theme::Themed;
 
+use crate::cache::{self, Fetched};
 use crate::config::Config;
+use crate::ui::{self, ListFormat};
 use crate::MemoryAction;
 use crate::api;
 
-pub async fn handle(action: MemoryAction, config: &Config, verbose: bool) -> Result<()> {
+/// Cache file name for the last memory status, served when offline
+const MEMORY_STATUS_CACHE: &str = "memory_status";
+
+/// Results `memory search` returns without `--limit` or `default_limit`
+const SEARCH_LIMIT: usize = 10;
+
+/// Memories `memory list` shows without `--limit` or `default_limit`
+const LIST_LIMIT: usize = 20;
+
+/// How many memories to scan when computing stats client-side
+const STATS_SCAN_LIMIT: usize = 1000;
+
+/// Sort keys for `memory search`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum SearchSort {
+    /// Relevance score
+    Score,
+    /// Creation date
+    Date,
+}
+
+/// Sort keys for `memory list`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum ListSort {
+    /// Creation date
+    Date,
+}
+
+pub async fn handle(action: MemoryAction, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
     match action {
-        MemoryAction::Status { deep } => status(deep, config, verbose).await,
-        MemoryAction::Search { query, limit, user } => search(&query, limit, user, config, verbose).await,
-        MemoryAction::Index { content, file, tags } => index(content, file, tags, config, verbose).await,
-        MemoryAction::List { limit, user } => list(limit, user, config, verbose).await,
-        MemoryAction::Clear { user, force } => clear(&user, force, config, verbose).await,
+        MemoryAction::Status { deep, direct } => status(deep, direct, config, verbose, quiet).await,
+        MemoryAction::Search {
+            query,
+            limit,
+            user,
+            tags,
+            any_tag,
+            sort,
+            asc,
+            desc: _,
+            context,
+            open,
+            include_preview,
+            no_preview,
+            preview_length,
+            explain,
+        } => {
+            // Previews show on a terminal by default; piped output only gets them on request
+            let show_preview = !no_preview && (include_preview || verbose || std::io::stdout().is_terminal());
+            let preview = show_preview.then_some(preview_length);
+            let limit = config.limit(limit, SEARCH_LIMIT);
+            search(&query, limit, user, &tags, any_tag, sort, asc, context, open, preview, explain, config, verbose, quiet)
+                .await
+        }
+        MemoryAction::Index { content, file, from_url, tags, skip_duplicates, rate } => {
+            index(content, file, from_url, tags, skip_duplicates, rate, config, verbose, quiet).await
+        }
+        MemoryAction::List { limit, user, cursor, sort: ListSort::Date, asc, desc: _, format, preview_length } => {
+            list(config.limit(limit, LIST_LIMIT), user, cursor, asc, format, preview_length, config, verbose, quiet).await
+        }
+        MemoryAction::Stats { user } => stats(user, config, verbose, quiet).await,
+        MemoryAction::Clear { user, force, dry_run } => clear(&user, force, dry_run, config, verbose, quiet).await,
+        MemoryAction::Import { file, dry_run } => import(&file, dry_run, config, verbose, quiet).await,
     }
 }
 
-async fn status(deep: bool, config: &Config, verbose: bool) -> Result<()> {
-    println!("{}", "PAM Memory Status".bold());
-    println!("{}", "─".repeat(40));
+async fn status(deep: bool, direct: bool, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
+    ui::header("PAM Memory Status", quiet);
 
-    // Get memory stats from API
-    match api::client::get_memory_status(&config.api_url).await {
-        Ok(stats) => {
-            println!("{} Memory system: {}", "•".green(), "Online".green());
-            println!("  Total memories:    {}", stats.total_memories);
-            println!("  Total sessions:    {}", stats.total_sessions);
-            println!("  Total reflections: {}", stats.total_reflections);
+    // Get memory stats from API, or straight from Postgres with --direct
+    let outcome = if direct {
+        if verbose {
+            println!("Connecting to {}:{}/{}", config.db_host, config.db_port, config.db_name);
+        }
+        api::db::get_memory_status(config).await.map(Fetched::fresh)
+    } else {
+        cache::fetch_or_cached(MEMORY_STATUS_CACHE, config, api::client::get_memory_status(&config.api_url)).await
+    };
+
+    match outcome {
+        Ok(fetched) => {
+            let source = match fetched.cached_label() {
+                Some(label) => label.warning(),
+                None if direct => "Online (direct)".success(),
+                None => "Online".success(),
+            };
+            let stats = fetched.data;
+            println!("{} Memory system: {}", "•".success(), source);
+            println!("  Total memories:    {}", ui::count_or_unknown(stats.total_memories));
+            println!("  Total sessions:    {}", ui::count_or_unknown(stats.total_sessions));
+            println!("  Total reflections: {}", ui::count_or_unknown(stats.total_reflections));
+            let unmatched = [stats.total_memories, stats.total_sessions, stats.total_reflections]
+                .iter()
+                .any(Option::is_none);
+            if direct && unmatched && !deep {
+                println!("  {}", "Some tables weren't recognized; use --deep to see every table".dimmed());
+            }
 
             if deep {
                 println!("\n{}", "Database Tables".bold());
                 for table in &stats.tables {
-                    println!("  {} {}: {} rows", "•".cyan(), table.name, table.row_count);
+                    println!("  {} {}: {} rows", "•".primary(), table.name, table.row_count);
                 }
             }
         }
-        Err(e) => {
-            println!("{} Memory system: {} - {}", "•".red(), "Error".red(), e);
-        }
+        Err(e) => ui::fail_error("Memory system", &e),
     }
 
     Ok(())
 }
 
-async fn search(query: &str, limit: usize, user: Option<String>, config: &Config, verbose: bool) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+async fn search(
+    query: &str,
+    limit: usize,
+    users: Vec<String>,
+    tags: &[String],
+    any_tag: bool,
+    sort: SearchSort,
+    ascending: bool,
+    context: Option<usize>,
+    open: Option<usize>,
+    preview: Option<Option<usize>>,
+    explain: bool,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
     if verbose {
         println!("Searching memories for: \"{}\"", query);
+        if !tags.is_empty() {
+            println!("Tags ({}): {}", if any_tag { "any" } else { "all" }, tags.join(", "));
+        }
     }
 
-    println!("{}", format!("Memory Search: \"{}\"", query).bold());
-    println!("{}", "─".repeat(40));
+    ui::header(&format!("Memory Search: \"{}\"", query), quiet);
+
+    let multi_user = users.len() > 1;
+    let outcome = if multi_user {
+        search_users(query, limit, &users, tags, any_tag, explain, config).await
+    } else {
+        let user = users.into_iter().next();
+        api::client::search_memories(&config.api_url, query, limit, user.as_deref(), tags, any_tag, explain)
+            .await
+            .map(|results| results.into_iter().map(|r| (user.clone(), r)).collect::<Vec<_>>())
+    };
+
+    match outcome {
+        Ok(mut results) => {
+            results.sort_by(|(_, a), (_, b)| {
+                let ordering = match sort {
+                    SearchSort::Score => a.relevance_score.total_cmp(&b.relevance_score),
+                    // ISO 8601 timestamps order correctly as strings
+                    SearchSort::Date => a.created_at.cmp(&b.created_at),
+                };
+                if ascending { ordering } else { ordering.reverse() }
+            });
+
+            let full_memories = match context {
+                Some(_) => fetch_full_memories(&results, config, quiet).await,
+                None => Vec::new(),
+            };
 
-    match api::client::search_memories(&config.api_url, query, limit, user.as_deref()).await {
-        Ok(results) => {
             if results.is_empty() {
-                println!("{}", "No memories found.".yellow());
+                ui::empty(ui::Empty::SearchResults, quiet);
             } else {
-                for (i, result) in results.iter().enumerate() {
-                    println!("\n{} {}", format!("[{}]", i + 1).cyan(), result.title.bold());
+                if explain && results.iter().all(|(_, r)| r.explanation.is_none()) && !quiet {
+                    println!(
+                        "{}",
+                        "The backend doesn't explain rankings; showing query terms found in each result instead"
+                            .dimmed()
+                    );
+                }
+                for (i, (user, result)) in results.iter().enumerate() {
+                    println!("\n{} {}", format!("[{}]", i + 1).primary(), result.title.bold());
+                    if multi_user {
+                        println!("    User:    {}", user.as_deref().unwrap_or("-"));
+                    }
                     println!("    Session: {}", result.session_id);
                     println!("    Date:    {}", result.created_at);
-                    println!("    Score:   {:.2}", result.relevance_score);
-                    if verbose {
-                        println!("    Preview: {}", &result.content[..result.content.len().min(200)]);
+                    println!("    Score:   {}", relevance_bar(result.relevance_score));
+                    if !result.tags.is_empty() {
+                        println!("    Tags:    {}", result.tags.join(", "));
+                    }
+                    if explain {
+                        println!("    Why:     {}", explain_result(query, result));
+                    }
+                    match (context, full_memories.get(i)) {
+                        (Some(n), Some(Ok(memory))) => print_match_context(&memory.content, query, n),
+                        (Some(_), Some(Err(e))) => println!("    {} Could not load full memory: {}", "✗".error(), e),
+                        _ => {
+                            if let Some(max) = preview {
+                                println!("    Preview: {}", ui::preview_line(&preview_text(&result.content, max), 13));
+                            }
+                        }
                     }
                 }
-                println!("\n{} {} memories found", "✓".green(), results.len());
+                println!("\n{} {} memories found", "✓".success(), results.len());
+            }
+
+            if let Some(n) = open {
+                open_result(&results, n, &full_memories, config, quiet).await;
             }
         }
         Err(e) => {
-            println!("{} Search failed: {}", "✗".red(), e);
+            ui::fail_error("Search failed", &e);
         }
     }
 
     Ok(())
 }
 
-async fn index(content: Option<String>, file: Option<String>, tags: Vec<String>, config: &Config, verbose: bool) -> Result<()> {
-    let text = match (content, file) {
-        (Some(c), _) => c,
-        (None, Some(f)) => std::fs::read_to_string(&f)?,
-        (None, None) => {
+/// One-line ranking rationale: the backend's factors when it sent them,
+/// otherwise which query terms appear in the title or content
+fn explain_result(query: &str, result: &api::client::MemorySearchResult) -> String {
+    if let Some(explanation) = &result.explanation {
+        let mut factors = Vec::new();
+        if !explanation.matched_terms.is_empty() {
+            factors.push(format!("matched {}", explanation.matched_terms.join(", ")));
+        }
+        if let Some(distance) = explanation.embedding_distance {
+            factors.push(format!("distance {:.3}", distance));
+        }
+        if let Some(boost) = explanation.recency_boost {
+            factors.push(format!("recency {:+.3}", boost));
+        }
+        if !factors.is_empty() {
+            return factors.join(" · ");
+        }
+    }
+
+    let text = format!("{}\n{}", result.title, result.content).to_lowercase();
+    let mut terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    let mut seen = std::collections::HashSet::new();
+    terms.retain(|t| seen.insert(t.clone()));
+    let matched: Vec<&str> = terms.iter().filter(|t| text.contains(t.as_str())).map(String::as_str).collect();
+    let overlap = match matched.len() {
+        0 => "no query terms in the text (semantic match)".to_string(),
+        n => format!("{}/{} query terms: {}", n, terms.len(), matched.join(", ")),
+    };
+    format!("score {:.3} · {}", result.relevance_score, overlap)
+}
+
+/// Show the full text of result `n` (1-based) in the pager, reusing it if
+/// `--context` already loaded it
+async fn open_result(
+    results: &[(Option<String>, api::client::MemorySearchResult)],
+    n: usize,
+    loaded: &[Result<api::client::Memory>],
+    config: &Config,
+    quiet: bool,
+) {
+    let Some((_, result)) = n.checked_sub(1).and_then(|i| results.get(i)) else {
+        ui::fail(format_args!("--open {} is out of range (1-{})", n, results.len()));
+        return;
+    };
+
+    let content = match loaded.get(n - 1) {
+        Some(Ok(memory)) => memory.content.clone(),
+        _ => {
+            let spinner = ui::spinner("Loading full memory...", quiet);
+            let outcome = api::client::get_memory(&config.api_url, &result.session_id).await;
+            spinner.finish_and_clear();
+            match outcome {
+                Ok(memory) => memory.content,
+                Err(e) => {
+                    ui::fail(format_args!("Could not load memory {}: {}", result.session_id, e));
+                    return;
+                }
+            }
+        }
+    };
+
+    ui::page(&format!("{}\nSession: {}\nDate:    {}\n\n{}", result.title, result.session_id, result.created_at, content));
+}
+
+/// Load the full memory behind each search result, in result order
+async fn fetch_full_memories(
+    results: &[(Option<String>, api::client::MemorySearchResult)],
+    config: &Config,
+    quiet: bool,
+) -> Vec<Result<api::client::Memory>> {
+    use futures::stream::{self, StreamExt};
+
+    let spinner = ui::spinner("Loading full memories...", quiet);
+    let memories = stream::iter(results)
+        .map(|(_, result)| api::client::get_memory(&config.api_url, &result.session_id))
+        .buffered(config.concurrency())
+        .collect()
+        .await;
+    spinner.finish_and_clear();
+    memories
+}
+
+/// Print the lines of `content` that mention any query term, with `n` lines
+/// either side, like `grep -C`. Separate groups are split by `--`.
+fn print_match_context(content: &str, query: &str, n: usize) {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    let lines: Vec<&str> = content.lines().collect();
+    let matches: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| {
+            let line = line.to_lowercase();
+            terms.iter().any(|t| line.contains(t.as_str()))
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    if matches.is_empty() {
+        println!("    {}", "(query terms not found verbatim; match is semantic)".dimmed());
+        return;
+    }
+
+    // Merge overlapping windows into contiguous ranges
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for &m in &matches {
+        let (start, end) = (m.saturating_sub(n), (m + n).min(lines.len() - 1));
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    let width = lines.len().to_string().len();
+    for (r, &(start, end)) in ranges.iter().enumerate() {
+        if r > 0 {
+            println!("    {}", "--".dimmed());
+        }
+        for (i, line) in lines.iter().enumerate().take(end + 1).skip(start) {
+            let number = format!("{:>width$}", i + 1, width = width);
+            let text = ui::truncate_display(line, ui::terminal_width().saturating_sub(width + 7).max(20));
+            if matches.binary_search(&i).is_ok() {
+                println!("    {}: {}", number.success(), text);
+            } else {
+                println!("    {}- {}", number.dimmed(), text.dimmed());
+            }
+        }
+    }
+}
+
+/// Width of the relevance bar in search results
+const RELEVANCE_BAR_WIDTH: usize = 10;
+
+/// Render a 0–1 score as a colored bar, e.g. `████████░░ 0.82`
+fn relevance_bar(score: f64) -> String {
+    let filled = (score.clamp(0.0, 1.0) * RELEVANCE_BAR_WIDTH as f64).round() as usize;
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(RELEVANCE_BAR_WIDTH - filled));
+    let bar = if score >= 0.75 {
+        bar.success()
+    } else if score >= 0.5 {
+        bar.warning()
+    } else {
+        bar.error()
+    };
+    format!("{} {:.2}", bar, score)
+}
+
+/// Search several users' memories concurrently and merge the results.
+///
+/// Results are ranked by relevance, deduplicated by session id, and trimmed
+/// to `limit`. Per-user failures are reported but don't abort the search.
+async fn search_users(
+    query: &str,
+    limit: usize,
+    users: &[String],
+    tags: &[String],
+    any_tag: bool,
+    explain: bool,
+    config: &Config,
+) -> Result<Vec<(Option<String>, api::client::MemorySearchResult)>> {
+    use futures::stream::{self, StreamExt};
+
+    let batches: Vec<_> = stream::iter(users)
+        .map(|user| async move {
+            let outcome =
+                api::client::search_memories(&config.api_url, query, limit, Some(user), tags, any_tag, explain).await;
+            (user, outcome)
+        })
+        .buffer_unordered(config.concurrency())
+        .collect()
+        .await;
+
+    let mut merged = Vec::new();
+    let mut errors = Vec::new();
+    for (user, outcome) in batches {
+        match outcome {
+            Ok(results) => merged.extend(results.into_iter().map(|r| (Some(user.clone()), r))),
+            Err(e) => errors.push((user, e)),
+        }
+    }
+
+    // Every user failing is a failed search; some failing still counts for --strict
+    if errors.len() == users.len() && !errors.is_empty() {
+        return Err(errors.swap_remove(0).1);
+    }
+    if !errors.is_empty() {
+        ui::mark_failed();
+        for (user, e) in &errors {
+            println!("{} Search failed for {}: {}", "⚠".warning(), user, e);
+        }
+    }
+
+    merged.sort_by(|a, b| b.1.relevance_score.total_cmp(&a.1.relevance_score));
+
+    let mut seen = std::collections::HashSet::new();
+    merged.retain(|(_, r)| seen.insert(r.session_id.clone()));
+    merged.truncate(limit);
+    Ok(merged)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn index(
+    content: Option<String>,
+    mut file: Vec<String>,
+    mut from_url: Vec<String>,
+    mut tags: Vec<String>,
+    skip_duplicates: bool,
+    rate: Option<f64>,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    if let Some(rate) = rate.filter(|r| !r.is_finite() || *r <= 0.0) {
+        ui::fail(format_args!("--rate must be a number above zero (got {})", rate));
+        return Ok(());
+    }
+
+    let is_batch = file.len() + from_url.len() > 1 || file.iter().any(|f| std::path::Path::new(f).is_dir());
+    if content.is_none() && is_batch {
+        let sources = match batch_sources(&file, &from_url) {
+            Ok(sources) => sources,
+            Err(e) => {
+                ui::fail(e);
+                return Ok(());
+            }
+        };
+        return index_batch(sources, &tags, skip_duplicates, rate, config, verbose, quiet).await;
+    }
+
+    let text = match (content, file.pop(), from_url.pop()) {
+        (Some(c), _, _) => c,
+        (None, Some(f), _) => std::fs::read_to_string(&f)?,
+        (None, None, Some(url)) => {
+            let spinner = ui::spinner(&format!("Fetching {}...", url), quiet);
+            let outcome = api::client::fetch_url_text(&url).await;
+            spinner.finish_and_clear();
+            match outcome {
+                Ok(text) => {
+                    tags.push(format!("source:{}", url));
+                    text
+                }
+                Err(e) => {
+                    ui::fail(format_args!("Failed to fetch {}: {}", url, e));
+                    return Ok(());
+                }
+            }
+        }
+        (None, None, None) => {
             // Read from stdin
             use std::io::Read;
             let mut buf = String::new();
@@ -94,61 +483,498 @@ async fn index(content: Option<String>, file: Option<String>, tags: Vec<String>,
         println!("Indexing {} characters with tags: {:?}", text.len(), tags);
     }
 
-    println!("Indexing content...");
+    if skip_duplicates {
+        let hash = api::client::content_hash(&text);
+        match api::client::memory_exists(&config.api_url, &hash).await {
+            Ok(Some(true)) => {
+                println!("{} Already indexed (sha256 {}), skipping", "•".primary(), &hash[..12]);
+                return Ok(());
+            }
+            Ok(Some(false)) => {}
+            Ok(None) => println!(
+                "{} Backend can't check for duplicates; indexing anyway (it receives the hash to dedupe)",
+                "⚠".warning()
+            ),
+            Err(e) => {
+                ui::fail_error("Duplicate check failed", &e);
+                return Ok(());
+            }
+        }
+    }
+
+    let spinner = ui::spinner("Indexing content...", quiet);
+    let outcome = api::client::index_memory(&config.api_url, &text, &tags).await;
+    spinner.finish_and_clear();
 
-    match api::client::index_memory(&config.api_url, &text, &tags).await {
+    match outcome {
         Ok(id) => {
-            println!("{} Memory indexed with ID: {}", "✓".green(), id);
+            println!("{} Memory indexed with ID: {}", "✓".success(), id);
         }
         Err(e) => {
-            println!("{} Indexing failed: {}", "✗".red(), e);
+            ui::fail_error("Indexing failed", &e);
+        }
+    }
+
+    Ok(())
+}
+
+/// One file or URL queued by a batch `memory index`
+enum IndexSource {
+    File(std::path::PathBuf),
+    Url(String),
+}
+
+impl std::fmt::Display for IndexSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndexSource::File(path) => write!(f, "{}", path.display()),
+            IndexSource::Url(url) => write!(f, "{}", url),
+        }
+    }
+}
+
+/// How one batch item ended
+enum Indexed {
+    Done(String),
+    Duplicate,
+}
+
+/// Expand `--file` arguments (directories become the regular, non-hidden
+/// files directly inside them, by name) and append the URLs
+fn batch_sources(files: &[String], urls: &[String]) -> Result<Vec<IndexSource>> {
+    let mut sources = Vec::new();
+    for file in files {
+        let path = std::path::Path::new(file);
+        if !path.is_dir() {
+            sources.push(IndexSource::File(path.to_path_buf()));
+            continue;
+        }
+
+        let mut entries: Vec<_> = std::fs::read_dir(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", file, e))?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| p.is_file() && !p.file_name().is_some_and(|n| n.to_string_lossy().starts_with('.')))
+            .collect();
+        entries.sort();
+        sources.extend(entries.into_iter().map(IndexSource::File));
+    }
+    sources.extend(urls.iter().cloned().map(IndexSource::Url));
+    Ok(sources)
+}
+
+/// Token bucket: refills at `rate` tokens per second, holding at most one
+/// second's worth, so bursts never exceed the configured rate
+struct RateLimiter {
+    rate: f64,
+    tokens: f64,
+    refilled_at: std::time::Instant,
+}
+
+impl RateLimiter {
+    fn new(rate: f64) -> Self {
+        RateLimiter { rate, tokens: 1.0, refilled_at: std::time::Instant::now() }
+    }
+
+    /// Wait until a token is available, then take it
+    async fn acquire(limiter: &tokio::sync::Mutex<RateLimiter>) {
+        loop {
+            let wait = {
+                let mut bucket = limiter.lock().await;
+                let now = std::time::Instant::now();
+                let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+                bucket.tokens = (bucket.tokens + elapsed * bucket.rate).min(bucket.rate.max(1.0));
+                bucket.refilled_at = now;
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                (1.0 - bucket.tokens) / bucket.rate
+            };
+            tokio::time::sleep(std::time::Duration::from_secs_f64(wait)).await;
+        }
+    }
+}
+
+/// Index each source as its own memory, at most `max_concurrency` requests
+/// in flight and, with `rate`, at most that many requests per second
+async fn index_batch(
+    sources: Vec<IndexSource>,
+    tags: &[String],
+    skip_duplicates: bool,
+    rate: Option<f64>,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    use futures::stream::{self, StreamExt};
+
+    if sources.is_empty() {
+        println!("{}", "No files to index.".warning());
+        return Ok(());
+    }
+
+    let total = sources.len();
+    let limiter = rate.map(|rate| tokio::sync::Mutex::new(RateLimiter::new(rate)));
+    let limiter = limiter.as_ref();
+    let spinner = ui::spinner(&format!("Indexing 0/{}...", total), quiet);
+    let started = std::time::Instant::now();
+
+    let mut outcomes = stream::iter(&sources)
+        .map(|source| async move {
+            let outcome = index_source(source, tags, skip_duplicates, limiter, config).await;
+            (source, outcome)
+        })
+        .buffer_unordered(config.concurrency());
+
+    let (mut indexed, mut skipped, mut failed, mut done) = (0, 0, 0, 0);
+    while let Some((source, outcome)) = outcomes.next().await {
+        done += 1;
+        spinner.set_message(format!("Indexing {}/{}...", done, total));
+        match outcome {
+            Ok(Indexed::Done(id)) => {
+                indexed += 1;
+                if verbose {
+                    spinner.suspend(|| println!("{} {} indexed as {}", "✓".success(), source, id));
+                }
+            }
+            Ok(Indexed::Duplicate) => {
+                skipped += 1;
+                if verbose {
+                    spinner.suspend(|| println!("{} {} already indexed, skipped", "•".primary(), source));
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                spinner.suspend(|| println!("{} {} failed: {}", "⚠".warning(), source, e));
+            }
         }
     }
+    spinner.finish_and_clear();
+
+    let elapsed = started.elapsed().as_secs_f64();
+    let throughput = format!("{:.1}s, {:.1} items/s", elapsed, total as f64 / elapsed.max(0.001));
+    let skipped = if skipped > 0 { format!(", {} already indexed", skipped) } else { String::new() };
+    if failed == 0 {
+        println!("{} Indexed {} of {} items{} ({})", "✓".success(), indexed, total, skipped, throughput);
+    } else {
+        ui::mark_failed();
+        println!(
+            "{} Indexed {} of {} items{} ({} failed; {})",
+            "⚠".warning(),
+            indexed,
+            total,
+            skipped,
+            failed,
+            throughput
+        );
+    }
 
     Ok(())
 }
 
-async fn list(limit: usize, user: Option<String>, config: &Config, verbose: bool) -> Result<()> {
-    println!("{}", "Recent Memories".bold());
-    println!("{}", "─".repeat(40));
+/// Read or fetch one batch item, then index it once the rate limiter allows
+async fn index_source(
+    source: &IndexSource,
+    tags: &[String],
+    skip_duplicates: bool,
+    limiter: Option<&tokio::sync::Mutex<RateLimiter>>,
+    config: &Config,
+) -> Result<Indexed> {
+    let mut tags = tags.to_vec();
+    let text = match source {
+        IndexSource::File(path) => std::fs::read_to_string(path)?,
+        IndexSource::Url(url) => {
+            tags.push(format!("source:{}", url));
+            api::client::fetch_url_text(url).await?
+        }
+    };
+
+    if skip_duplicates {
+        if let Some(limiter) = limiter {
+            RateLimiter::acquire(limiter).await;
+        }
+        let hash = api::client::content_hash(&text);
+        if api::client::memory_exists(&config.api_url, &hash).await? == Some(true) {
+            return Ok(Indexed::Duplicate);
+        }
+    }
+
+    if let Some(limiter) = limiter {
+        RateLimiter::acquire(limiter).await;
+    }
+    api::client::index_memory(&config.api_url, &text, &tags).await.map(Indexed::Done)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn list(
+    limit: usize,
+    user: Option<String>,
+    cursor: Option<String>,
+    ascending: bool,
+    format: ListFormat,
+    preview_length: Option<usize>,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    if format == ListFormat::Ndjson {
+        // Streamed in server order: sorting would mean buffering every page
+        return ui::stream_ndjson(cursor, |cursor| {
+            let user = user.clone();
+            async move { api::client::list_memories(&config.api_url, limit, user.as_deref(), cursor.as_deref()).await }
+        })
+        .await;
+    }
+    if format == ListFormat::Tsv {
+        return ui::stream_tsv(
+            &["session_id", "created_at", "tags", "preview"],
+            cursor,
+            |cursor| {
+                let user = user.clone();
+                async move { api::client::list_memories(&config.api_url, limit, user.as_deref(), cursor.as_deref()).await }
+            },
+            |m| vec![m.session_id.clone(), m.created_at.to_rfc3339(), m.tags.join(","), m.preview.clone()],
+        )
+        .await;
+    }
+
+    ui::header("Recent Memories", quiet);
+
+    match api::client::list_memories(&config.api_url, limit, user.as_deref(), cursor.as_deref()).await {
+        Ok(page) => {
+            let mut memories = page.items;
+            let preview = |memory: &api::client::MemoryEntry| preview_text(&memory.preview, preview_length);
+            if ascending {
+                memories.sort_by_key(|m| m.created_at);
+            } else {
+                memories.sort_by_key(|m| std::cmp::Reverse(m.created_at));
+            }
 
-    match api::client::list_memories(&config.api_url, limit, user.as_deref()).await {
-        Ok(memories) => {
             if memories.is_empty() {
-                println!("{}", "No memories found.".yellow());
+                ui::empty(ui::Empty::Memories, quiet);
+            } else if format == ListFormat::Table {
+                let mut table = ui::table(&["Session", "Age", "Preview"]);
+                for memory in &memories {
+                    ui::table_row(
+                        &mut table,
+                        vec![
+                            memory.session_id.clone(),
+                            format_age(memory.created_at),
+                            preview(memory),
+                        ],
+                    );
+                }
+                println!("{}", table);
             } else {
                 for memory in &memories {
-                    let age = chrono::Utc::now().signed_duration_since(memory.created_at);
-                    let age_str = if age.num_hours() < 1 {
-                        format!("{}m ago", age.num_minutes())
-                    } else if age.num_days() < 1 {
-                        format!("{}h ago", age.num_hours())
-                    } else {
-                        format!("{}d ago", age.num_days())
-                    };
-
-                    println!("{} {} ({})", "•".cyan(), memory.session_id, age_str.dimmed());
+                    let age_str = format_age(memory.created_at);
+
+                    println!("{} {} ({})", "•".primary(), memory.session_id, age_str.dimmed());
                     if verbose {
-                        println!("    {}", &memory.preview);
+                        println!("    {}", ui::preview_line(&preview(memory), 4));
                     }
                 }
             }
+            ui::next_page_hint(page.next_cursor.as_deref());
+        }
+        Err(e) => {
+            ui::fail_error("Failed to list memories", &e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Flatten `text` to one line, cut to `max` characters when set.
+///
+/// Flattened first so `--preview-length` counts only visible text.
+fn preview_text(text: &str, max: Option<usize>) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match max {
+        Some(max) => ui::truncate_display(&flat, max),
+        None => flat,
+    }
+}
+
+/// Relative age like "5m ago", "3h ago", or "2d ago"
+///
+/// Future timestamps mean the local clock is off, so they show as
+/// "just now" and trigger a one-time warning.
+fn format_age(created_at: chrono::DateTime<chrono::Utc>) -> String {
+    let age = chrono::Utc::now().signed_duration_since(created_at);
+    if age < chrono::Duration::zero() {
+        ui::warn_clock_skew();
+        return "just now".to_string();
+    }
+    if age.num_minutes() < 1 {
+        "just now".to_string()
+    } else if age.num_hours() < 1 {
+        format!("{}m ago", age.num_minutes())
+    } else if age.num_days() < 1 {
+        format!("{}h ago", age.num_hours())
+    } else {
+        format!("{}d ago", age.num_days())
+    }
+}
+
+async fn stats(user: Option<String>, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
+    ui::header("Memory Statistics", quiet);
+
+    // `sampled` is set when the fallback couldn't see every memory
+    let outcome = match api::client::get_memory_stats(&config.api_url, user.as_deref()).await {
+        Ok(Some(stats)) => Ok((stats, false)),
+        Ok(None) => {
+            if verbose {
+                println!("Stats endpoint unavailable, aggregating the latest {} memories", STATS_SCAN_LIMIT);
+            }
+            api::client::list_memories(&config.api_url, STATS_SCAN_LIMIT, user.as_deref(), None)
+                .await
+                .map(|page| {
+                    let sampled = page.next_cursor.is_some() || page.items.len() >= STATS_SCAN_LIMIT;
+                    (aggregate_stats(&page.items), sampled)
+                })
+        }
+        Err(e) => Err(e),
+    };
+
+    match outcome {
+        Ok((mut stats, sampled)) => {
+            if sampled {
+                println!(
+                    "  Total memories: ≥ {} {}",
+                    stats.total_memories,
+                    format!("(only the latest {} were scanned; figures below cover those)", stats.total_memories).dimmed()
+                );
+            } else {
+                println!("  Total memories: {}", stats.total_memories);
+            }
+            if let Some(oldest) = stats.oldest {
+                println!("  Oldest:         {}", oldest.format("%Y-%m-%d %H:%M UTC"));
+            }
+            if let Some(newest) = stats.newest {
+                println!("  Newest:         {}", newest.format("%Y-%m-%d %H:%M UTC"));
+            }
+
+            if stats.tags.is_empty() {
+                println!();
+                ui::empty(ui::Empty::TaggedMemories, quiet);
+            } else {
+                stats.tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+                println!("\n{}", "By Tag:".primary());
+                for tag in &stats.tags {
+                    println!("  {} {}: {}", "•".primary(), tag.tag, tag.count);
+                }
+            }
         }
         Err(e) => {
-            println!("{} Failed to list memories: {}", "✗".red(), e);
+            ui::fail_error("Failed to get memory stats", &e);
         }
     }
 
     Ok(())
 }
 
-async fn clear(user: &str, force: bool, config: &Config, _verbose: bool) -> Result<()> {
+/// Build stats from a page of memories when the backend can't
+fn aggregate_stats(memories: &[api::client::MemoryEntry]) -> api::client::MemoryStats {
+    let mut counts: std::collections::HashMap<&str, i64> = std::collections::HashMap::new();
+    for tag in memories.iter().flat_map(|m| &m.tags) {
+        *counts.entry(tag).or_default() += 1;
+    }
+
+    api::client::MemoryStats {
+        total_memories: memories.len() as i64,
+        tags: counts
+            .into_iter()
+            .map(|(tag, count)| api::client::TagCount { tag: tag.to_string(), count })
+            .collect(),
+        oldest: memories.iter().map(|m| m.created_at).min(),
+        newest: memories.iter().map(|m| m.created_at).max(),
+    }
+}
+
+/// Memories shown as samples by `clear --dry-run`
+const CLEAR_SAMPLES: usize = 5;
+
+/// Page size used when counting a user's memories
+const CLEAR_PAGE_SIZE: usize = 100;
+
+/// What `clear` would delete for one user
+struct ClearScope {
+    count: usize,
+    /// The backend returned a full page without a cursor, so there may be more than `count`
+    capped: bool,
+    samples: Vec<api::client::MemoryEntry>,
+}
+
+impl ClearScope {
+    /// "42", or "at least 100" when the count may be short
+    fn count_label(&self) -> String {
+        if self.capped {
+            format!("at least {}", self.count)
+        } else {
+            self.count.to_string()
+        }
+    }
+}
+
+/// Count every memory `clear` would delete for `user`, keeping the first few as samples
+async fn clear_scope(user: &str, config: &Config) -> Result<ClearScope> {
+    let mut count = 0;
+    let mut samples = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = api::client::list_memories(&config.api_url, CLEAR_PAGE_SIZE, Some(user), cursor.as_deref()).await?;
+        let full = page.items.len() >= CLEAR_PAGE_SIZE;
+        count += page.items.len();
+        let room = CLEAR_SAMPLES.saturating_sub(samples.len());
+        samples.extend(page.items.into_iter().take(room));
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            // Older backends return a bare list: a full page may not be everything
+            None => return Ok(ClearScope { count, capped: full, samples }),
+        }
+    }
+}
+
+async fn clear(user: &str, force: bool, dry_run: bool, config: &Config, _verbose: bool, quiet: bool) -> Result<()> {
+    if dry_run {
+        ui::header(&format!("Clear Memories for {} (dry run)", user), quiet);
+        let spinner = ui::spinner("Counting memories...", quiet);
+        let outcome = clear_scope(user, config).await;
+        spinner.finish_and_clear();
+
+        match outcome {
+            Ok(scope) if scope.count == 0 => {
+                println!("{} {} has no memories; nothing would be deleted", "✓".success(), user)
+            }
+            Ok(scope) => {
+                println!("{}", "Would delete, e.g.:".bold());
+                for memory in &scope.samples {
+                    println!(
+                        "  • {} ({}) {}",
+                        memory.session_id,
+                        format_age(memory.created_at).dimmed(),
+                        ui::preview_line(&memory.preview, 8 + memory.session_id.len())
+                    );
+                }
+                println!("\n{} {} memories for {} would be deleted", "•".primary(), scope.count_label(), user);
+            }
+            Err(e) => ui::fail_error("Failed to list memories", &e),
+        }
+        return Ok(());
+    }
+
     if !force {
-        use dialoguer::Confirm;
-        let confirmed = Confirm::new()
-            .with_prompt(format!("Clear all memories for {}? This cannot be undone.", user))
-            .default(false)
-            .interact()?;
+        // Show the scope in the prompt when we can; the count is best-effort
+        let scope = match clear_scope(user, config).await {
+            Ok(scope) if scope.capped => format!("at least {} memories", scope.count),
+            Ok(scope) => format!("all {} memories", scope.count),
+            Err(_) => "all memories".to_string(),
+        };
+        let confirmed = ui::confirm(
+            &format!("Clear {} for {}? This cannot be undone.", scope, user),
+            false,
+        )?;
 
         if !confirmed {
             println!("Cancelled.");
@@ -156,16 +982,139 @@ async fn clear(user: &str, force: bool, config: &Config, _verbose: bool) -> Resu
         }
     }
 
-    println!("Clearing memories for {}...", user);
+    let spinner = ui::spinner(&format!("Clearing memories for {}...", user), quiet);
+    let outcome = api::client::clear_memories(&config.api_url, user).await;
+    spinner.finish_and_clear();
 
-    match api::client::clear_memories(&config.api_url, user).await {
+    match outcome {
         Ok(count) => {
-            println!("{} Cleared {} memories", "✓".green(), count);
+            println!("{} Cleared {} memories", "✓".success(), count);
         }
         Err(e) => {
-            println!("{} Failed to clear memories: {}", "✗".red(), e);
+            ui::fail_error("Failed to clear memories", &e);
+        }
+    }
+
+    Ok(())
+}
+
+/// One memory read from an export file
+#[derive(serde::Deserialize)]
+struct ImportEntry {
+    #[serde(alias = "preview")]
+    content: String,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+async fn import(file: &str, dry_run: bool, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
+    ui::header(&format!("Import Memories: {}", file), quiet);
+
+    let text = match std::fs::read_to_string(file) {
+        Ok(text) => text,
+        Err(e) => {
+            ui::fail(format_args!("Failed to read {}: {}", file, e));
+            return Ok(());
+        }
+    };
+
+    let is_json = file.ends_with(".json") || text.trim_start().starts_with(['[', '{']);
+    let entries = if is_json {
+        match parse_json_export(&text) {
+            Ok(entries) => entries,
+            Err(e) => {
+                ui::fail_error("Invalid JSON export", &e);
+                return Ok(());
+            }
+        }
+    } else {
+        parse_markdown_export(&text)
+    };
+
+    if entries.is_empty() {
+        println!("{}", "No memories found in file.".warning());
+        return Ok(());
+    }
+
+    if dry_run {
+        let tagged = entries.iter().filter(|e| !e.tags.is_empty()).count();
+        println!("{} Would index {} memories ({} with tags)", "•".primary(), entries.len(), tagged);
+        return Ok(());
+    }
+
+    let mut imported = 0;
+    let mut failed = 0;
+    for (i, entry) in entries.iter().enumerate() {
+        let spinner = ui::spinner(&format!("Indexing {}/{}...", i + 1, entries.len()), quiet);
+        let outcome = api::client::index_memory(&config.api_url, &entry.content, &entry.tags).await;
+        spinner.finish_and_clear();
+
+        match outcome {
+            Ok(id) => {
+                imported += 1;
+                if verbose {
+                    println!("{} [{}] indexed as {}", "✓".success(), i + 1, id);
+                }
+            }
+            Err(e) => {
+                failed += 1;
+                println!("{} [{}] failed: {}", "⚠".warning(), i + 1, e);
+            }
         }
     }
 
+    if failed == 0 {
+        println!("{} Imported {} memories", "✓".success(), imported);
+    } else {
+        ui::mark_failed();
+        println!("{} Imported {} of {} memories ({} failed)", "⚠".warning(), imported, entries.len(), failed);
+    }
+
     Ok(())
 }
+
+/// Accepts a bare array of entries or `{"memories": [...]}`
+fn parse_json_export(text: &str) -> Result<Vec<ImportEntry>> {
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Export {
+        List(Vec<ImportEntry>),
+        Wrapped { memories: Vec<ImportEntry> },
+    }
+
+    Ok(match serde_json::from_str(text)? {
+        Export::List(entries) | Export::Wrapped { memories: entries } => entries,
+    })
+}
+
+/// Each `## ` section is one memory; an optional `Tags: a, b` line sets its tags
+fn parse_markdown_export(text: &str) -> Vec<ImportEntry> {
+    let mut entries = Vec::new();
+    let mut current: Option<(Vec<String>, Vec<String>)> = None;
+
+    let mut flush = |section: Option<(Vec<String>, Vec<String>)>| {
+        if let Some((lines, tags)) = section {
+            let content = lines.join("\n").trim().to_string();
+            if !content.is_empty() {
+                entries.push(ImportEntry { content, tags });
+            }
+        }
+    };
+
+    for line in text.lines() {
+        if let Some(title) = line.strip_prefix("## ") {
+            flush(current.take());
+            current = Some((vec![title.trim().to_string()], Vec::new()));
+        } else if let Some((lines, tags)) = current.as_mut() {
+            match line.trim().strip_prefix("Tags:") {
+                Some(list) => tags.extend(
+                    list.split(',').map(|t| t.trim().to_string()).filter(|t| !t.is_empty()),
+                ),
+                None => lines.push(line.to_string()),
+            }
+        }
+    }
+    flush(current);
+
+    entries
+}
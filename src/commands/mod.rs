@@ -6,3 +6,6 @@ pub mod context;
 pub mod reflect;
 pub mod chat;
 pub mod jira;
+pub mod dashboard;
+pub mod update;
+pub mod raw;
@@ -0,0 +1,106 @@
+//! `pam api` - send a raw authenticated request to an unwrapped endpoint
+
+use anyhow::Result;
+use crate::theme::Themed;
+
+use crate::api;
+use crate::config::Config;
+use crate::ui;
+
+/// HTTP methods `pam api` accepts
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl From<HttpMethod> for reqwest::Method {
+    fn from(method: HttpMethod) -> Self {
+        match method {
+            HttpMethod::Get => reqwest::Method::GET,
+            HttpMethod::Post => reqwest::Method::POST,
+            HttpMethod::Put => reqwest::Method::PUT,
+            HttpMethod::Patch => reqwest::Method::PATCH,
+            HttpMethod::Delete => reqwest::Method::DELETE,
+        }
+    }
+}
+
+/// Send `method path` with an optional JSON body and print the response.
+///
+/// JSON responses are pretty-printed to stdout (compact with `raw`); anything
+/// else is printed as-is. A non-2xx status still prints the body, then fails.
+#[allow(clippy::too_many_arguments)]
+pub async fn handle(
+    method: HttpMethod,
+    path: &str,
+    body: Option<&str>,
+    user: Option<String>,
+    retries: u32,
+    raw: bool,
+    config: &Config,
+    quiet: bool,
+) -> Result<()> {
+    let body = match body.map(read_body).transpose() {
+        Ok(body) => body,
+        Err(e) => {
+            ui::fail_error("Invalid body", &e);
+            return Ok(());
+        }
+    };
+    let user_email = config.resolve_user_email(user);
+
+    let mut attempt = 0;
+    let outcome = loop {
+        let spinner = ui::spinner(&format!("{} {}...", reqwest::Method::from(method), path), quiet);
+        let outcome =
+            api::client::raw_request(&config.api_url, method.into(), path, body.as_ref(), user_email.as_deref()).await;
+        spinner.finish_and_clear();
+
+        match outcome {
+            Err(e) if attempt < retries && api::client::is_network_error(&e) => {
+                attempt += 1;
+                eprintln!("{} Request failed: {} (retrying {}/{})", "⚠".warning(), e, attempt, retries);
+                tokio::time::sleep(std::time::Duration::from_secs(attempt.into())).await;
+            }
+            outcome => break outcome,
+        }
+    };
+
+    let resp = match outcome {
+        Ok(resp) => resp,
+        Err(e) => {
+            ui::fail_error("Request failed", &e);
+            return Ok(());
+        }
+    };
+
+    match serde_json::from_str::<serde_json::Value>(&resp.body) {
+        Ok(json) if raw => println!("{}", json),
+        Ok(json) => println!("{}", serde_json::to_string_pretty(&json)?),
+        Err(_) if !resp.body.is_empty() => println!("{}", resp.body),
+        Err(_) => {}
+    }
+
+    if !resp.status.is_success() {
+        ui::fail(format_args!("Server returned {}", resp.status));
+    }
+
+    Ok(())
+}
+
+/// Load the body from its argument, an `@file`, or stdin (`-`), checking it is JSON
+fn read_body(arg: &str) -> Result<serde_json::Value> {
+    let raw = if arg == "-" {
+        std::io::read_to_string(std::io::stdin())?
+    } else if let Some(path) = arg.strip_prefix('@') {
+        std::fs::read_to_string(path).map_err(|e| anyhow::anyhow!("could not read {}: {}", path, e))?
+    } else {
+        arg.to_string()
+    };
+
+    serde_json::from_str(&raw).map_err(|e| anyhow::anyhow!("not valid JSON ({})", e))
+}
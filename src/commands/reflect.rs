@@ -2,51 +2,115 @@
 
 use anyhow::Result;
 use colored::Colorize;
-use chrono::Utc;
+use crate::theme::Themed;
+use chrono::{DateTime, Utc};
 
+use crate::cache;
 use crate::config::Config;
+use crate::ui;
 use crate::api;
+use crate::commands::context;
+
+/// Output and scope options for `pam reflect`
+pub struct ReflectOptions {
+    pub export: bool,
+    pub send_slack: bool,
+    pub since_last: bool,
+    /// Print the reflection as JSON or YAML, sending all other output to stderr
+    pub format: Option<ui::DataFormat>,
+    /// Id of a saved reflection to compare the new one against
+    pub compare: Option<String>,
+    /// Print only the numbered action items (the full reflection is still saved)
+    pub action_items_only: bool,
+    /// Refresh the context bundle first if it is stale
+    pub refresh_before: bool,
+}
 
 pub async fn handle(
     session: Option<String>,
-    export: bool,
     user: Option<String>,
+    options: ReflectOptions,
     config: &Config,
     verbose: bool,
+    quiet: bool,
 ) -> Result<()> {
-    let user_email = user.or(config.user_email.clone()).unwrap_or_else(|| {
-        println!("{} No user email specified. Use --user or set PAM_USER_EMAIL", "⚠".yellow());
+    let ReflectOptions { export, send_slack, since_last, format, compare, action_items_only, refresh_before } = options;
+    // JSON/YAML and action-items output drop the decoration; what's left goes to stderr via `note`
+    let to_stderr = format.is_some() || action_items_only;
+    let quiet = quiet || to_stderr;
+    let verbose = verbose && !to_stderr;
+
+    let user_email = config.resolve_user_email(user).unwrap_or_else(|| {
+        ui::note(
+            to_stderr,
+            format!(
+                "{} No user email specified. Use --user, set PAM_USER_EMAIL, or set git's user.email",
+                "⚠".warning()
+            ),
+        );
         "unknown@mergeworld.com".to_string()
     });
 
-    println!("{}", "PAM Reflection Loop".bold());
-    println!("{}", "─".repeat(40));
-    println!("User: {}", user_email.cyan());
+    // Taken before fetching so sessions created mid-run are picked up next time
+    let started_at = Utc::now();
+    let last_reflected = if since_last { last_reflected_at(&user_email, config) } else { None };
+    if since_last && last_reflected.is_none() && verbose {
+        println!("No previous reflection recorded, using today's sessions");
+    }
 
-    if let Some(ref sid) = session {
-        println!("Session: {}", sid);
-    } else {
-        println!("Scope: Today's sessions");
+    ui::header("PAM Reflection Loop", quiet);
+    if !quiet {
+        println!("User: {}", user_email.primary());
+
+        if let Some(ref sid) = session {
+            println!("Session: {}", sid);
+        } else if let Some(since) = last_reflected {
+            println!("Scope: Sessions since {}", since.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M"));
+        } else {
+            println!("Scope: Today's sessions");
+        }
+        println!();
     }
 
-    println!("\n{}", "Analyzing conversations...".dimmed());
+    if refresh_before {
+        context::refresh_if_stale(config, verbose, quiet && !to_stderr, to_stderr).await;
+    }
+
+    // Fetch the baseline first so a bad id fails before the slow generation step
+    let previous = match &compare {
+        Some(id) => match api::client::get_reflection(&config.api_url, id).await {
+            Ok(previous) => Some(previous),
+            Err(e) => {
+                ui::fail_error("Cannot compare", &e);
+                return Ok(());
+            }
+        },
+        None => None,
+    };
 
     // Get sessions to reflect on
+    let scoped_to_session = session.is_some();
     let sessions = if let Some(sid) = session {
         vec![sid]
     } else {
-        // Get today's sessions
-        match api::client::get_today_sessions(&config.api_url, &user_email).await {
+        // Get today's sessions, or everything since the last reflection
+        let spinner = ui::spinner("Analyzing conversations...", quiet);
+        let outcome = match last_reflected {
+            Some(since) => api::client::get_sessions_since(&config.api_url, &user_email, since).await,
+            None => api::client::get_today_sessions(&config.api_url, &user_email).await,
+        };
+        spinner.finish_and_clear();
+        match outcome {
             Ok(s) => s,
             Err(e) => {
-                println!("{} Failed to get sessions: {}", "✗".red(), e);
+                fail(to_stderr, "Failed to get sessions", &e);
                 return Ok(());
             }
         }
     };
 
     if sessions.is_empty() {
-        println!("{}", "No sessions found to reflect on.".yellow());
+        ui::note(to_stderr, "No sessions found to reflect on.".warning());
         return Ok(());
     }
 
@@ -55,39 +119,35 @@ pub async fn handle(
     }
 
     // Generate reflection
-    println!("\n{}", "Generating reflection...".dimmed());
+    // The endpoint returns the whole reflection at once, so show elapsed time while it works
+    let spinner = ui::timed_spinner("Generating reflection...", quiet);
+    let outcome = api::client::generate_reflection(&config.api_url, &user_email, &sessions).await;
+    spinner.finish_and_clear();
 
-    match api::client::generate_reflection(&config.api_url, &user_email, &sessions).await {
+    match outcome {
         Ok(reflection) => {
-            println!("{} Reflection generated", "✓".green());
-
-            println!("\n{}", "═".repeat(50).cyan());
-            println!("{}", "REFLECTION SUMMARY".bold().cyan());
-            println!("{}", "═".repeat(50).cyan());
-
-            println!("\n{}", "What Worked:".green().bold());
-            for item in &reflection.what_worked {
-                println!("  {} {}", "✓".green(), item);
-            }
-
-            println!("\n{}", "What Could Be Improved:".yellow().bold());
-            for item in &reflection.what_failed {
-                println!("  {} {}", "•".yellow(), item);
-            }
-
-            println!("\n{}", "Key Learnings:".cyan().bold());
-            for learning in &reflection.learnings {
-                println!("  {} {}", "💡".to_string(), learning);
+            if !scoped_to_session {
+                if let Err(e) = record_reflected_at(&user_email, started_at, config) {
+                    tracing::debug!("Failed to record reflection time: {}", e);
+                }
             }
 
-            if !reflection.action_items.is_empty() {
-                println!("\n{}", "Action Items:".magenta().bold());
+            if let Some(format) = format {
+                ui::print_data(&reflection, format)?;
+            } else if action_items_only {
+                if reflection.action_items.is_empty() {
+                    ui::note(to_stderr, "No action items.".warning());
+                }
                 for (i, item) in reflection.action_items.iter().enumerate() {
-                    println!("  {}. {}", i + 1, item);
+                    println!("{}. {}", i + 1, item);
                 }
+            } else {
+                print_reflection(&reflection, quiet);
             }
 
-            println!("\n{}", "═".repeat(50).cyan());
+            if let (Some(previous), Some(id)) = (&previous, &compare) {
+                print_comparison(previous, &reflection, id);
+            }
 
             // Export if requested
             if export {
@@ -96,7 +156,7 @@ pub async fn handle(
                     Utc::now().format("%Y%m%d_%H%M%S")
                 );
                 export_reflection(&filename, &reflection)?;
-                println!("\n{} Exported to: {}", "✓".green(), filename);
+                ui::note(to_stderr, format!("\n{} Exported to: {}", "✓".success(), filename));
             }
 
             // Save to database
@@ -105,26 +165,203 @@ pub async fn handle(
             }
 
             match api::client::save_reflection(&config.api_url, &user_email, &reflection).await {
-                Ok(id) => {
-                    println!("{} Reflection saved (ID: {})", "✓".green(), id);
+                Ok(id) if !to_stderr => {
+                    println!("{} Reflection saved (ID: {})", "✓".success(), id);
                 }
+                Ok(_) => {}
                 Err(e) => {
-                    println!("{} Failed to save reflection: {}", "⚠".yellow(), e);
+                    ui::note(to_stderr, format!("{} Failed to save reflection: {}", "⚠".warning(), e));
                 }
             }
+
+            if send_slack {
+                post_to_slack(&reflection, &user_email, config, to_stderr, quiet).await;
+            }
         }
-        Err(e) => {
-            println!("{} Reflection generation failed: {}", "✗".red(), e);
-        }
+        Err(e) => fail(to_stderr, "Reflection generation failed", &e),
     }
 
     Ok(())
 }
 
+/// Report a failure like [`ui::fail_error`], but on stderr when stdout
+/// carries only a YAML or plain-text result
+fn fail(to_stderr: bool, context: &str, e: &anyhow::Error) {
+    if ui::json_errors() || !to_stderr {
+        ui::fail_error(context, e);
+    } else {
+        ui::mark_failed();
+        eprintln!("{} {}: {}", "✗".error(), context, e);
+    }
+}
+
+fn print_reflection(reflection: &api::client::Reflection, quiet: bool) {
+    if !quiet {
+        println!("{} Reflection generated", "✓".success());
+
+        println!("\n{}", "═".repeat(50).primary());
+        println!("{}", "REFLECTION SUMMARY".bold().primary());
+        println!("{}", "═".repeat(50).primary());
+    }
+
+    println!("\n{}", "What Worked:".success().bold());
+    for item in &reflection.what_worked {
+        println!("  {} {}", "✓".success(), item);
+    }
+
+    println!("\n{}", "What Could Be Improved:".warning().bold());
+    for item in &reflection.what_failed {
+        println!("  {} {}", "•".warning(), item);
+    }
+
+    println!("\n{}", "Key Learnings:".primary().bold());
+    for learning in &reflection.learnings {
+        println!("  💡 {}", learning);
+    }
+
+    if !reflection.action_items.is_empty() {
+        println!("\n{}", "Action Items:".magenta().bold());
+        for (i, item) in reflection.action_items.iter().enumerate() {
+            println!("  {}. {}", i + 1, item);
+        }
+    }
+
+    if !quiet {
+        println!("\n{}", "═".repeat(50).primary());
+    }
+}
+
+/// Show which action items are still open, resolved, or new since `previous`,
+/// and which learnings are new
+fn print_comparison(previous: &api::client::Reflection, current: &api::client::Reflection, id: &str) {
+    // Items are free text, so compare ignoring case and spacing
+    let normalize = |s: &str| s.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    let contains = |items: &[String], item: &str| items.iter().any(|i| normalize(i) == normalize(item));
+
+    println!("\n{} {}", "Compared with reflection".bold(), id.primary());
+
+    let still_open: Vec<&String> =
+        current.action_items.iter().filter(|i| contains(&previous.action_items, i)).collect();
+    let resolved: Vec<&String> =
+        previous.action_items.iter().filter(|i| !contains(&current.action_items, i)).collect();
+    let added: Vec<&String> =
+        current.action_items.iter().filter(|i| !contains(&previous.action_items, i)).collect();
+
+    println!("\n{}", "Action Items:".magenta().bold());
+    for item in &still_open {
+        println!("  {} {} {}", "●".warning(), item, "(still open)".warning());
+    }
+    for item in &resolved {
+        println!("  {} {} {}", "✓".success(), item.dimmed(), "(resolved)".success());
+    }
+    for item in &added {
+        println!("  {} {} {}", "+".primary(), item, "(new)".primary());
+    }
+    println!(
+        "  {} still open, {} resolved, {} new",
+        still_open.len(),
+        resolved.len(),
+        added.len()
+    );
+
+    let new_learnings: Vec<&String> =
+        current.learnings.iter().filter(|l| !contains(&previous.learnings, l)).collect();
+    println!("\n{}", "Learnings:".primary().bold());
+    for learning in &new_learnings {
+        println!("  {} {}", "+".primary(), learning);
+    }
+    println!(
+        "  {} new, {} repeated from last time",
+        new_learnings.len(),
+        current.learnings.len() - new_learnings.len()
+    );
+}
+
+fn last_reflected_cache_name(user_email: &str) -> String {
+    format!("last_reflection_{}", user_email.replace(|c: char| !c.is_ascii_alphanumeric(), "_"))
+}
+
+/// When `reflect` last ran over this user's sessions, if ever
+fn last_reflected_at(user_email: &str, config: &Config) -> Option<DateTime<Utc>> {
+    cache::read::<DateTime<Utc>>(&last_reflected_cache_name(user_email), &config.api_url).map(|e| e.data)
+}
+
+fn record_reflected_at(user_email: &str, at: DateTime<Utc>, config: &Config) -> Result<()> {
+    cache::write(&last_reflected_cache_name(user_email), &config.api_url, &at)
+}
+
+/// Slack rejects section text longer than this
+const SLACK_SECTION_LIMIT: usize = 3000;
+
+/// Share the reflection in Slack. Failures only warn; the reflection is already saved.
+async fn post_to_slack(
+    reflection: &api::client::Reflection,
+    user_email: &str,
+    config: &Config,
+    to_stderr: bool,
+    quiet: bool,
+) {
+    let Some(webhook) = config.slack_webhook_url.as_deref() else {
+        ui::note(
+            to_stderr,
+            format!("{} --send-slack needs a webhook: pam config set slack_webhook_url <url>", "⚠".warning()),
+        );
+        return;
+    };
+
+    let spinner = ui::spinner("Posting to Slack...", quiet);
+    let outcome = api::client::post_slack_webhook(webhook, &slack_blocks(reflection, user_email)).await;
+    spinner.finish_and_clear();
+
+    match outcome {
+        Ok(()) => ui::note(to_stderr, format!("{} Posted to Slack", "✓".success())),
+        Err(e) => ui::note(to_stderr, format!("{} Failed to post to Slack: {}", "⚠".warning(), e)),
+    }
+}
+
+fn slack_blocks(reflection: &api::client::Reflection, user_email: &str) -> serde_json::Value {
+    let section = |title: &str, lines: Vec<String>| {
+        let text = ui::truncate_display(&format!("*{}*\n{}", title, lines.join("\n")), SLACK_SECTION_LIMIT);
+        serde_json::json!({ "type": "section", "text": { "type": "mrkdwn", "text": text } })
+    };
+    let bullets = |items: &[String]| items.iter().map(|i| format!("• {}", i)).collect::<Vec<_>>();
+
+    let mut blocks = vec![
+        serde_json::json!({
+            "type": "header",
+            "text": { "type": "plain_text", "text": "PAM Reflection" }
+        }),
+        serde_json::json!({
+            "type": "context",
+            "elements": [{
+                "type": "mrkdwn",
+                "text": format!("{} · {}", user_email, Utc::now().format("%Y-%m-%d %H:%M UTC"))
+            }]
+        }),
+        section("What Worked", bullets(&reflection.what_worked)),
+        section("What Could Be Improved", bullets(&reflection.what_failed)),
+        section("Key Learnings", bullets(&reflection.learnings)),
+    ];
+    if !reflection.action_items.is_empty() {
+        let numbered = reflection
+            .action_items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| format!("{}. {}", i + 1, item))
+            .collect();
+        blocks.push(section("Action Items", numbered));
+    }
+
+    serde_json::json!({
+        "text": format!("PAM Reflection for {}", user_email),
+        "blocks": blocks,
+    })
+}
+
 fn export_reflection(filename: &str, reflection: &api::client::Reflection) -> Result<()> {
     let mut content = String::new();
 
-    content.push_str(&format!("# PAM Reflection\n"));
+    content.push_str("# PAM Reflection\n");
     content.push_str(&format!("*Generated: {}*\n\n", Utc::now().format("%Y-%m-%d %H:%M UTC")));
 
     content.push_str("## What Worked\n");
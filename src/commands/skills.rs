@@ -1,153 +1,1209 @@
 //! Skills management commands
 
+use std::io::{IsTerminal, Write};
+use std::path::PathBuf;
+
 use anyhow::Result;
 use colored::Colorize;
+use crate::theme::Themed;
 
+use crate::cache::{self, Fetched};
 use crate::config::Config;
-use crate::SkillsAction;
+use crate::explorer;
+use crate::ui::{self, ListFormat};
+use crate::{FavAction, ParamsAction, SkillsAction};
 use crate::api;
 
-pub async fn handle(action: SkillsAction, config: &Config, verbose: bool) -> Result<()> {
+pub async fn handle(action: SkillsAction, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
     match action {
-        SkillsAction::List { detailed } => list(detailed, config, verbose).await,
-        SkillsAction::Test { skill, params } => test(&skill, params, config, verbose).await,
-        SkillsAction::Invoke { skill, params, user } => invoke(&skill, &params, user, config, verbose).await,
-        SkillsAction::Log { skill, limit } => log(skill, limit, config, verbose).await,
+        SkillsAction::List { detailed, refresh, format, favorites, sort, risk, enabled, disabled } => {
+            let filter = SkillFilter {
+                favorites,
+                risk,
+                enabled: match (enabled, disabled) {
+                    (true, _) => Some(true),
+                    (_, true) => Some(false),
+                    _ => None,
+                },
+            };
+            list(detailed, refresh, format, sort, filter, config, verbose, quiet).await
+        }
+        SkillsAction::Fav { action } => fav(action, config, quiet).await,
+        SkillsAction::Params { action } => saved_params(action, quiet),
+        SkillsAction::Test { skill, params, truncate, full, save_default_params } => {
+            let max_chars = if full { None } else { Some(truncate) };
+            test(&skill, params, max_chars, save_default_params, config, verbose, quiet).await
+        }
+        SkillsAction::Invoke {
+            skill,
+            params,
+            template,
+            emails_file,
+            date,
+            query,
+            user,
+            dry_run,
+            yes,
+            select,
+            watch,
+            archive,
+            retries,
+            format,
+            interactive,
+            save_default_params,
+        } => {
+            let builder = ParamBuilder { emails_file, date, query };
+            let params = match build_params(params.as_deref(), template, builder) {
+                Ok(params) => params,
+                Err(e) => {
+                    ui::fail_error("Invalid parameters", &e);
+                    return Ok(());
+                }
+            };
+            let select = select.as_deref();
+            invoke(
+                &skill,
+                &params,
+                user,
+                dry_run,
+                yes,
+                select,
+                watch,
+                archive,
+                retries,
+                format,
+                interactive,
+                save_default_params,
+                config,
+                verbose,
+                quiet,
+            )
+            .await
+        }
+        SkillsAction::Describe { skill } => describe(&skill, config, verbose, quiet).await,
+        SkillsAction::Log { skill, limit, all_users, follow: true, interval, format, .. } => {
+            follow_log(skill, config.limit(limit, LOG_LIMIT), all_users, interval, format, config, quiet).await
+        }
+        SkillsAction::Log { skill, limit, all_users, cursor, format, summary, .. } => {
+            let limit = config.limit(limit, LOG_LIMIT);
+            log(skill, limit, all_users, cursor, format, summary, config, verbose, quiet).await
+        }
     }
 }
 
-async fn list(detailed: bool, config: &Config, verbose: bool) -> Result<()> {
-    println!("{}", "PAM Skills".bold());
-    println!("{}", "─".repeat(40));
+/// Sort keys for `skills list`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum SkillSort {
+    /// Most invoked first
+    Usage,
+    /// Skill key, alphabetically
+    Name,
+    /// Safest first
+    Risk,
+}
+
+/// Which skills `skills list` shows
+struct SkillFilter {
+    favorites: bool,
+    /// Risk level to match, case-insensitively
+    risk: Option<String>,
+    /// `Some(true)` for enabled only, `Some(false)` for disabled only
+    enabled: Option<bool>,
+}
+
+impl SkillFilter {
+    fn is_active(&self) -> bool {
+        self.risk.is_some() || self.enabled.is_some()
+    }
+
+    fn matches(&self, skill: &api::client::Skill, favorites: &[String]) -> bool {
+        (!self.favorites || favorites.contains(&skill.skill_key))
+            && self.risk.as_ref().is_none_or(|r| skill.risk_level.eq_ignore_ascii_case(r))
+            && self.enabled.is_none_or(|e| skill.enabled == e)
+    }
+}
 
-    match api::client::list_skills(&config.api_url).await {
+/// Order risk levels from safest; unknown levels sort last
+fn risk_rank(risk_level: &str) -> u8 {
+    match risk_level.to_lowercase().as_str() {
+        "safe" => 0,
+        "moderate" => 1,
+        "high" => 2,
+        "dangerous" | "critical" => 3,
+        _ => 4,
+    }
+}
+
+/// Entries `skills log` shows (or fetches per page) without `--limit` or `default_limit`
+const LOG_LIMIT: usize = 20;
+
+/// Cache file name for the skill list
+const SKILLS_CACHE: &str = "skills";
+
+/// Fetch the skill list, serving it from the local cache while fresh.
+///
+/// Pass `refresh` to bypass the cache; a successful fetch always rewrites it.
+/// Offline, or when the API is unreachable, an older cached list is used.
+pub async fn fetch_skills(config: &Config, refresh: bool) -> Result<Fetched<Vec<api::client::Skill>>> {
+    if !refresh && !config.offline {
+        if let Some(skills) = cache::read_fresh(SKILLS_CACHE, &config.api_url, config.cache_ttl_secs) {
+            return Ok(Fetched::fresh(skills));
+        }
+    }
+
+    cache::fetch_or_cached(SKILLS_CACHE, config, api::client::list_skills(&config.api_url)).await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn list(
+    detailed: bool,
+    refresh: bool,
+    format: ListFormat,
+    sort: SkillSort,
+    filter: SkillFilter,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    if !matches!(format, ListFormat::Ndjson | ListFormat::Tsv) {
+        ui::header("PAM Skills", quiet);
+    }
+
+    let favorites = load_favorites();
+    let outcome = fetch_skills(config, refresh).await.map(|fetched| {
+        fetched.announce();
+        let mut skills = fetched.data;
+        skills.retain(|s| filter.matches(s, &favorites));
+        match sort {
+            SkillSort::Usage => skills.sort_by_key(|s| std::cmp::Reverse(s.usage_count)),
+            SkillSort::Name => skills.sort_by(|a, b| a.skill_key.cmp(&b.skill_key)),
+            SkillSort::Risk => skills.sort_by_key(|s| risk_rank(&s.risk_level)),
+        }
+        // Stable sort keeps the chosen order within each group
+        skills.sort_by_key(|s| !favorites.contains(&s.skill_key));
+        skills
+    });
+
+    match outcome {
+        Ok(skills) if filter.favorites && skills.is_empty() && !filter.is_active() => {
+            ui::empty(ui::Empty::FavoriteSkills, quiet);
+        }
+        Ok(skills) if filter.is_active() && skills.is_empty() => {
+            ui::empty(ui::Empty::FilteredSkills, quiet);
+        }
+        Ok(skills) if format == ListFormat::Ndjson => {
+            for skill in &skills {
+                println!("{}", serde_json::to_string(skill)?);
+            }
+        }
+        Ok(skills) if format == ListFormat::Tsv => {
+            println!("skill_key\trisk_level\tenabled\tusage_count\tdescription");
+            for skill in &skills {
+                let row = [
+                    skill.skill_key.clone(),
+                    skill.risk_level.clone(),
+                    skill.enabled.to_string(),
+                    skill.usage_count.to_string(),
+                    skill.description.clone(),
+                ];
+                println!("{}", ui::tsv_row(&row));
+            }
+        }
+        Ok(skills) if format == ListFormat::Table => {
+            let mut table = ui::table(&["Skill", "Risk", "Enabled", "Usage"]);
+            for skill in &skills {
+                ui::table_row(
+                    &mut table,
+                    vec![
+                        favorite_label(&skill.skill_key, &favorites),
+                        skill.risk_level.clone(),
+                        if skill.enabled { "yes" } else { "no" }.to_string(),
+                        skill.usage_count.to_string(),
+                    ],
+                );
+            }
+            println!("{}", table);
+            println!("{} {} skills available", "✓".success(), skills.len());
+        }
         Ok(skills) => {
             for skill in &skills {
-                let status_icon = if skill.enabled { "✓".green() } else { "○".dimmed() };
-                let risk_badge = match skill.risk_level.as_str() {
-                    "safe" => "safe".green(),
-                    "moderate" => "moderate".yellow(),
-                    _ => skill.risk_level.normal(),
-                };
+                let status_icon = if skill.enabled { "✓".success() } else { "○".dimmed() };
 
-                println!("\n{} {} [{}]", status_icon, skill.skill_key.bold(), risk_badge);
+                println!(
+                    "\n{} {} [{}]",
+                    status_icon,
+                    favorite_label(&skill.skill_key, &favorites).bold(),
+                    risk_badge(&skill.risk_level)
+                );
 
                 if detailed || verbose {
                     println!("    {}", skill.description.dimmed());
                     println!("    Usage: {} invocations", skill.usage_count);
                 }
             }
-            println!("\n{} {} skills available", "✓".green(), skills.len());
+            println!("\n{} {} skills available", "✓".success(), skills.len());
         }
         Err(e) => {
-            println!("{} Failed to list skills: {}", "✗".red(), e);
+            ui::fail_error("Failed to list skills", &e);
         }
     }
 
     Ok(())
 }
 
-async fn test(skill: &str, params: Option<String>, config: &Config, verbose: bool) -> Result<()> {
-    println!("{}", format!("Testing Skill: {}", skill).bold());
-    println!("{}", "─".repeat(40));
+/// File under the config dir holding bookmarked skill keys
+const FAVORITES_FILE: &str = "skill_favorites.json";
 
-    let test_params = params.unwrap_or_else(|| get_default_test_params(skill));
+fn favorites_path() -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join(FAVORITES_FILE))
+}
 
-    if verbose {
-        println!("Test params: {}", test_params);
+fn load_favorites() -> Vec<String> {
+    favorites_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_favorites(favorites: &[String]) -> Result<()> {
+    std::fs::write(favorites_path()?, serde_json::to_string_pretty(favorites)?)?;
+    Ok(())
+}
+
+/// File under the config dir mapping skill keys to saved default params
+const SAVED_PARAMS_FILE: &str = "skill_default_params.json";
+
+fn saved_params_path() -> Result<PathBuf> {
+    Ok(Config::config_dir()?.join(SAVED_PARAMS_FILE))
+}
+
+fn load_saved_params() -> std::collections::BTreeMap<String, serde_json::Value> {
+    saved_params_path()
+        .ok()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn write_saved_params(saved: &std::collections::BTreeMap<String, serde_json::Value>) -> Result<()> {
+    std::fs::write(saved_params_path()?, serde_json::to_string_pretty(saved)?)?;
+    Ok(())
+}
+
+/// Store `params` as the default `skills test` params for `skill`, reporting the outcome
+fn report_saved_params(skill: &str, params: &str, to_stderr: bool, quiet: bool) {
+    let outcome = serde_json::from_str(params).map_err(anyhow::Error::from).and_then(|params| {
+        let mut saved = load_saved_params();
+        saved.insert(skill.to_string(), params);
+        write_saved_params(&saved)
+    });
+    match outcome {
+        Ok(()) if !quiet => ui::note(to_stderr, format_args!("{} Saved as the default params for {}", "✓".success(), skill)),
+        Ok(()) => {}
+        Err(e) => ui::fail_error("Failed to save default params", &e),
     }
+}
 
-    println!("Running test...\n");
+fn saved_params(action: ParamsAction, quiet: bool) -> Result<()> {
+    let mut saved = load_saved_params();
 
+    match action {
+        ParamsAction::List => {
+            ui::header("Saved Skill Params", quiet);
+            if saved.is_empty() {
+                ui::empty(ui::Empty::SavedParams, quiet);
+            }
+            for (skill, params) in &saved {
+                println!("  {} {}", skill.bold(), params);
+            }
+        }
+        ParamsAction::Clear { skill } => {
+            if saved.remove(&skill).is_none() {
+                ui::fail(format_args!("No saved params for {}", skill));
+                return Ok(());
+            }
+            write_saved_params(&saved)?;
+            println!("{} Cleared saved params for {}; `skills test` uses the built-in defaults", "✓".success(), skill);
+        }
+    }
+
+    Ok(())
+}
+
+/// Skill key with a star when it is bookmarked
+fn favorite_label(skill_key: &str, favorites: &[String]) -> String {
+    if favorites.iter().any(|f| f == skill_key) {
+        format!("★ {}", skill_key)
+    } else {
+        skill_key.to_string()
+    }
+}
+
+async fn fav(action: FavAction, config: &Config, quiet: bool) -> Result<()> {
+    let mut favorites = load_favorites();
+
+    match action {
+        FavAction::Add { skill } => {
+            if favorites.contains(&skill) {
+                println!("{} {} is already a favorite", "•".primary(), skill);
+                return Ok(());
+            }
+            // Warn on typos, but don't block bookmarking when the API is unreachable
+            if let Ok(skills) = fetch_skills(config, false).await {
+                if !skills.data.iter().any(|s| s.skill_key == skill) {
+                    println!("{} No skill named {} is currently available", "⚠".warning(), skill);
+                }
+            }
+            favorites.push(skill.clone());
+            save_favorites(&favorites)?;
+            println!("{} Added {} to favorites", "✓".success(), skill);
+        }
+        FavAction::Remove { skill } => {
+            let before = favorites.len();
+            favorites.retain(|f| f != &skill);
+            if favorites.len() == before {
+                ui::fail(format_args!("{} is not a favorite", skill));
+                return Ok(());
+            }
+            save_favorites(&favorites)?;
+            println!("{} Removed {} from favorites", "✓".success(), skill);
+        }
+        FavAction::List => {
+            ui::header("Favorite Skills", quiet);
+            if favorites.is_empty() {
+                ui::empty(ui::Empty::FavoriteSkills, quiet);
+            }
+            for skill in &favorites {
+                println!("  ★ {}", skill);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Run a skill with test params; content output is cut at `max_chars` unless `None`
+async fn test(
+    skill: &str,
+    params: Option<String>,
+    max_chars: Option<usize>,
+    save_default_params: bool,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    ui::header(&format!("Testing Skill: {}", skill), quiet);
+
+    // Explicit params, then ones saved with --save-default-params, then the built-in defaults
+    let (test_params, source) = match params {
+        Some(params) => (params, "--params"),
+        None => match load_saved_params().remove(skill) {
+            Some(saved) => (saved.to_string(), "saved"),
+            None => (get_default_test_params(skill), "built-in"),
+        },
+    };
+
+    if verbose {
+        println!("Test params ({}): {}", source, test_params);
+    }
+
+    let spinner = ui::spinner("Running test...", quiet);
     let start = std::time::Instant::now();
+    let key = api::client::idempotency_key();
+    let outcome = api::client::invoke_skill(&config.api_url, skill, &test_params, Some("test@mergeworld.com"), &key).await;
+    spinner.finish_and_clear();
 
-    match api::client::invoke_skill(&config.api_url, skill, &test_params, Some("test@mergeworld.com")).await {
+    match outcome {
         Ok(result) => {
             let duration = start.elapsed();
 
-            println!("{} Skill executed successfully", "✓".green());
+            println!("{} Skill executed successfully", "✓".success());
             println!("Duration: {}ms", duration.as_millis());
+            if save_default_params {
+                report_saved_params(skill, &test_params, false, quiet);
+            }
 
             if let Some(content) = result.get("content").and_then(|v| v.as_str()) {
                 println!("\n{}", "Output:".bold());
-                // Show first 500 chars
-                let preview = if content.len() > 500 {
-                    format!("{}...", &content[..500])
-                } else {
-                    content.to_string()
-                };
-                println!("{}", preview);
+                match max_chars {
+                    Some(max) => println!("{}", ui::truncate_display(content, max)),
+                    None => println!("{}", content),
+                }
             } else {
                 println!("\n{}", "Result:".bold());
                 println!("{}", serde_json::to_string_pretty(&result)?);
             }
         }
         Err(e) => {
-            println!("{} Skill test failed: {}", "✗".red(), e);
+            ui::fail_error("Skill test failed", &e);
         }
     }
 
     Ok(())
 }
 
-async fn invoke(skill: &str, params: &str, user: Option<String>, config: &Config, verbose: bool) -> Result<()> {
-    let user_email = user.or(config.user_email.clone()).unwrap_or_else(|| "unknown@mergeworld.com".to_string());
+/// Load `--params` from its argument, an `@file`, or stdin (`-`), expanding
+/// `{{ENV_VAR}}` placeholders first when `template` is set.
+fn read_params(arg: &str, template: bool) -> Result<String> {
+    let raw = if arg == "-" {
+        std::io::read_to_string(std::io::stdin())?
+    } else if let Some(path) = arg.strip_prefix('@') {
+        std::fs::read_to_string(path).map_err(|e| anyhow::anyhow!("could not read {}: {}", path, e))?
+    } else {
+        arg.to_string()
+    };
+
+    let params = if template { expand_env_placeholders(&raw)? } else { raw };
+
+    serde_json::from_str::<serde_json::Value>(&params)
+        .map_err(|e| anyhow::anyhow!("not valid JSON ({})", e))?;
+    Ok(params)
+}
+
+/// Convenience flags that fill in common skill parameters
+struct ParamBuilder {
+    emails_file: Option<PathBuf>,
+    date: Option<chrono::NaiveDate>,
+    query: Option<String>,
+}
+
+/// Assemble the params JSON: `--params` (if any) as the base object, with
+/// builder flags setting their keys on top.
+fn build_params(params: Option<&str>, template: bool, builder: ParamBuilder) -> Result<String> {
+    let base = match params {
+        Some(arg) => read_params(arg, template)?,
+        None => "{}".to_string(),
+    };
+    let ParamBuilder { emails_file, date, query } = builder;
+    if emails_file.is_none() && date.is_none() && query.is_none() {
+        return Ok(base);
+    }
+
+    let mut value: serde_json::Value = serde_json::from_str(&base)?;
+    let Some(object) = value.as_object_mut() else {
+        anyhow::bail!("must be a JSON object to combine with --emails-file/--date/--query");
+    };
+    if let Some(path) = emails_file {
+        object.insert("emails".to_string(), read_emails(&path)?.into());
+    }
+    if let Some(date) = date {
+        object.insert("date".to_string(), date.format("%Y-%m-%d").to_string().into());
+    }
+    if let Some(query) = query {
+        object.insert("query".to_string(), query.into());
+    }
+    Ok(value.to_string())
+}
+
+/// Email addresses from a file, one per line; blank lines and `#` comments are ignored
+fn read_emails(path: &std::path::Path) -> Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))?;
+    let mut emails = Vec::new();
+    for (i, line) in content.lines().enumerate() {
+        let email = line.trim();
+        if email.is_empty() || email.starts_with('#') {
+            continue;
+        }
+        if !email.contains('@') || email.contains(char::is_whitespace) {
+            anyhow::bail!("{} line {}: '{}' is not an email address", path.display(), i + 1, email);
+        }
+        emails.push(email.to_string());
+    }
+    if emails.is_empty() {
+        anyhow::bail!("no email addresses in {}", path.display());
+    }
+    Ok(emails)
+}
+
+/// Replace every `{{NAME}}` with the value of environment variable `NAME`.
+///
+/// Fails listing all unset variables, so a template can be fixed in one go.
+/// Values are JSON-escaped, so placeholders belong inside string literals.
+fn expand_env_placeholders(template: &str) -> Result<String> {
+    let mut out = String::with_capacity(template.len());
+    let mut missing = Vec::new();
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else { break };
+        let name = rest[start + 2..start + 2 + len].trim();
+        let is_var = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        out.push_str(&rest[..start]);
+        if !is_var {
+            out.push_str(&rest[start..start + 4 + len]);
+        } else if let Ok(value) = std::env::var(name) {
+            // Escape for a JSON string literal, dropping the surrounding quotes
+            let escaped = serde_json::to_string(&value)?;
+            out.push_str(&escaped[1..escaped.len() - 1]);
+        } else if !missing.contains(&name) {
+            missing.push(name);
+        }
+        rest = &rest[start + 4 + len..];
+    }
+    out.push_str(rest);
+
+    if !missing.is_empty() {
+        anyhow::bail!("template variable(s) not set: {}", missing.join(", "));
+    }
+    Ok(out)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn invoke(
+    skill: &str,
+    params: &str,
+    user: Option<String>,
+    dry_run: bool,
+    yes: bool,
+    select: Option<&str>,
+    watch: Option<u64>,
+    archive: bool,
+    retries: u32,
+    format: Option<ui::DataFormat>,
+    interactive: bool,
+    save_default_params: bool,
+    config: &Config,
+    verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    if interactive && !std::io::stdout().is_terminal() {
+        ui::fail("--interactive needs a terminal; use --format json to pipe the result");
+        return Ok(());
+    }
+
+    let user_email = config
+        .resolve_user_email(user)
+        .unwrap_or_else(|| "unknown@mergeworld.com".to_string());
+
+    let selector = match select.map(parse_selector).transpose() {
+        Ok(selector) => selector,
+        Err(e) => {
+            ui::fail_error("Invalid --select expression", &e);
+            return Ok(());
+        }
+    };
+
+    if dry_run {
+        return print_dry_run(skill, params, &user_email, config, quiet);
+    }
+
+    // With --format, stdout carries only the document
+    let to_stderr = format.is_some();
+    if !yes && !confirm_risky_invocation(skill, params, to_stderr, config).await? {
+        ui::note(to_stderr, "Cancelled.");
+        return Ok(());
+    }
 
     if verbose {
-        println!("Invoking {} as {}", skill, user_email);
-        println!("Params: {}", params);
+        ui::note(to_stderr, format_args!("Invoking {} as {}", skill, user_email));
+        ui::note(to_stderr, format_args!("Params: {}", params));
     }
 
-    println!("Invoking {}...", skill.bold());
+    let Some(interval) = watch else {
+        return run_invocation(
+            skill,
+            params,
+            &user_email,
+            select,
+            selector.as_deref(),
+            archive,
+            retries,
+            format,
+            interactive,
+            save_default_params,
+            config,
+            quiet,
+        )
+        .await;
+    };
 
-    match api::client::invoke_skill(&config.api_url, skill, params, Some(&user_email)).await {
-        Ok(result) => {
-            println!("{} Skill completed", "✓".green());
+    // Watch mode: re-run on an interval until Ctrl-C
+    ui::install_interrupt_handler();
+    let interval = std::time::Duration::from_secs(interval.max(1));
+    loop {
+        print!("\x1B[2J\x1B[H");
+        println!(
+            "{}",
+            format!(
+                "Every {}s: {} - {} (Ctrl-C to stop)",
+                interval.as_secs(),
+                skill,
+                chrono::Local::now().format("%Y-%m-%d %H:%M:%S")
+            )
+            .dimmed()
+        );
+        println!();
 
-            if let Some(content) = result.get("content").and_then(|v| v.as_str()) {
-                println!("\n{}", content);
-            } else {
-                println!("{}", serde_json::to_string_pretty(&result)?);
+        run_invocation(
+            skill,
+            params,
+            &user_email,
+            select,
+            selector.as_deref(),
+            archive,
+            retries,
+            format,
+            false,
+            save_default_params,
+            config,
+            quiet,
+        )
+        .await?;
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Invoke a skill once and print its result (or the `--select`ed part of it).
+///
+/// Network errors are retried up to `retries` times with the same
+/// idempotency key, so the backend can tell a retry from a new run.
+#[allow(clippy::too_many_arguments)]
+async fn run_invocation(
+    skill: &str,
+    params: &str,
+    user_email: &str,
+    select: Option<&str>,
+    selector: Option<&[PathSegment]>,
+    archive: bool,
+    retries: u32,
+    format: Option<ui::DataFormat>,
+    interactive: bool,
+    save_default_params: bool,
+    config: &Config,
+    quiet: bool,
+) -> Result<()> {
+    // With --format, stdout carries only the document
+    let to_stderr = format.is_some();
+    let key = api::client::idempotency_key();
+    let mut attempt = 0;
+    let outcome = loop {
+        let spinner = ui::spinner(&format!("Invoking {}...", skill.bold()), quiet);
+        let outcome = api::client::invoke_skill(&config.api_url, skill, params, Some(user_email), &key).await;
+        spinner.finish_and_clear();
+
+        match outcome {
+            Err(e) if attempt < retries && api::client::is_network_error(&e) => {
+                attempt += 1;
+                ui::note(
+                    to_stderr,
+                    format_args!("{} Skill call failed: {} (retrying {}/{})", "⚠".warning(), e, attempt, retries),
+                );
+                tokio::time::sleep(std::time::Duration::from_secs(attempt.into())).await;
+            }
+            outcome => break outcome,
+        }
+    };
+
+    if save_default_params && outcome.is_ok() {
+        report_saved_params(skill, params, to_stderr, quiet);
+    }
+
+    if let (true, Ok(result)) = (archive, &outcome) {
+        match archive_result(skill, params, user_email, result, config) {
+            Ok(path) if !quiet => ui::note(to_stderr, format_args!("{} Archived to {}", "•".primary(), path.display())),
+            Ok(_) => {}
+            Err(e) => ui::fail_error("Failed to archive result", &e),
+        }
+    }
+
+    match outcome {
+        Ok(result) if interactive => match selector.map(|path| select_value(&result, path)) {
+            Some(None) => ui::fail(format_args!("Nothing in the result matches '{}'", select.unwrap_or_default())),
+            Some(Some(value)) => explorer::explore(value, selector_base(select.unwrap_or_default()))?,
+            None => explorer::explore(&result, "")?,
+        },
+        Ok(result) => match selector {
+            Some(path) => match (select_value(&result, path), format) {
+                (Some(value), Some(format)) => ui::print_data(value, format)?,
+                (Some(serde_json::Value::String(s)), None) => println!("{}", s),
+                (Some(value), None) => println!("{}", serde_json::to_string_pretty(value)?),
+                (None, _) => ui::fail(format_args!("Nothing in the result matches '{}'", select.unwrap_or_default())),
+            },
+            None => match format {
+                Some(format) => ui::print_data(&result, format)?,
+                None => {
+                    println!("{} Skill completed", "✓".success());
+
+                    if let Some(content) = result.get("content").and_then(|v| v.as_str()) {
+                        println!("\n{}", content);
+                    } else {
+                        println!("{}", serde_json::to_string_pretty(&result)?);
+                    }
+                }
+            },
+        },
+        Err(e) => {
+            ui::fail_error("Skill failed", &e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Save a skill result as `<skill>_<timestamp>.json`, wrapped with the
+/// params and user that produced it
+fn archive_result(
+    skill: &str,
+    params: &str,
+    user_email: &str,
+    result: &serde_json::Value,
+    config: &Config,
+) -> Result<PathBuf> {
+    let dir = config.skill_output_dir()?;
+    std::fs::create_dir_all(&dir)?;
+
+    let now = chrono::Utc::now();
+    let envelope = serde_json::json!({
+        "skill": skill,
+        "user": user_email,
+        "invoked_at": now.to_rfc3339(),
+        "params": serde_json::from_str::<serde_json::Value>(params)?,
+        "result": result,
+    });
+
+    // Millisecond names, plus a counter in case two runs still collide
+    let stem = format!("{}_{}", skill.replace(['/', '\\'], "_"), now.format("%Y%m%d_%H%M%S_%3f"));
+    let mut attempt = 1;
+    let (path, mut file) = loop {
+        let name = match attempt {
+            1 => format!("{}.json", stem),
+            n => format!("{}_{}.json", stem, n),
+        };
+        let path = dir.join(name);
+        match std::fs::OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => break (path, file),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => attempt += 1,
+            Err(e) => return Err(e.into()),
+        }
+    };
+    file.write_all(serde_json::to_string_pretty(&envelope)?.as_bytes())?;
+    Ok(path)
+}
+
+/// One step of a `--select` path
+enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// A `--select` expression without its optional leading `$.`, as shown in the explorer
+fn selector_base(expr: &str) -> &str {
+    let expr = expr.trim();
+    let expr = expr.strip_prefix('$').unwrap_or(expr);
+    expr.strip_prefix('.').unwrap_or(expr)
+}
+
+/// Parse a dotted path like `data.items[0].title` (a leading `$.` is allowed)
+fn parse_selector(expr: &str) -> Result<Vec<PathSegment>> {
+    let expr = selector_base(expr);
+
+    let mut segments = Vec::new();
+    for part in expr.split('.') {
+        let (key, mut rest) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if !key.is_empty() {
+            segments.push(PathSegment::Key(key.to_string()));
+        } else if rest.is_empty() {
+            anyhow::bail!("empty path segment in '{}'", expr);
+        }
+
+        while !rest.is_empty() {
+            let close = rest
+                .find(']')
+                .ok_or_else(|| anyhow::anyhow!("unclosed '[' in '{}'", part))?;
+            let index = rest[1..close]
+                .trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("'{}' is not an array index", &rest[1..close]))?;
+            segments.push(PathSegment::Index(index));
+            rest = &rest[close + 1..];
+            if !rest.is_empty() && !rest.starts_with('[') {
+                anyhow::bail!("unexpected '{}' after ']'", rest);
             }
         }
+    }
+
+    Ok(segments)
+}
+
+fn select_value<'a>(value: &'a serde_json::Value, path: &[PathSegment]) -> Option<&'a serde_json::Value> {
+    path.iter().try_fold(value, |current, segment| match segment {
+        PathSegment::Key(key) => current.get(key.as_str()),
+        PathSegment::Index(index) => current.get(*index),
+    })
+}
+
+async fn describe(skill: &str, config: &Config, verbose: bool, quiet: bool) -> Result<()> {
+    ui::header(&format!("Skill: {}", skill), quiet);
+
+    match api::client::get_skill_schema(&config.api_url, skill).await {
+        Ok(Some(schema)) => {
+            if let Some(description) = schema.get("description").and_then(|v| v.as_str()) {
+                println!("{}", description.dimmed());
+            }
+
+            let required: Vec<&str> = schema
+                .get("required")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).collect())
+                .unwrap_or_default();
+
+            match schema.get("properties").and_then(|v| v.as_object()) {
+                Some(properties) if !properties.is_empty() => {
+                    println!("\n{}", "Parameters:".bold());
+                    for (name, prop) in properties {
+                        let ty = prop.get("type").and_then(|v| v.as_str()).unwrap_or("any");
+                        let marker = if required.contains(&name.as_str()) {
+                            "required".warning()
+                        } else {
+                            "optional".dimmed()
+                        };
+                        println!("  {} {} ({}) [{}]", "•".primary(), name.bold(), ty, marker);
+                        if let Some(doc) = prop.get("description").and_then(|v| v.as_str()) {
+                            println!("      {}", doc.dimmed());
+                        }
+                    }
+                }
+                _ => println!("\n{}", "This skill takes no parameters.".dimmed()),
+            }
+
+            if verbose {
+                println!("\n{}", "Raw schema:".bold());
+                println!("{}", serde_json::to_string_pretty(&schema)?);
+            }
+        }
+        Ok(None) => {
+            println!("{} No schema published for this skill", "•".warning());
+            println!("\n{}", "Example params:".bold());
+            println!("{}", get_default_test_params(skill));
+        }
         Err(e) => {
-            println!("{} Skill failed: {}", "✗".red(), e);
+            ui::fail_error("Failed to describe skill", &e);
         }
     }
 
     Ok(())
 }
 
-async fn log(skill: Option<String>, limit: usize, config: &Config, _verbose: bool) -> Result<()> {
-    println!("{}", "Skill Audit Log".bold());
-    println!("{}", "─".repeat(40));
+/// Ask for confirmation before invoking a skill that isn't marked "safe".
+///
+/// Returns `true` when the invocation should proceed.
+async fn confirm_risky_invocation(skill: &str, params: &str, to_stderr: bool, config: &Config) -> Result<bool> {
+    let risk_level = match fetch_skills(config, false).await {
+        Ok(skills) => skills
+            .data
+            .into_iter()
+            .find(|s| s.skill_key == skill)
+            .map(|s| s.risk_level)
+            .unwrap_or_else(|| "unknown".to_string()),
+        Err(e) => {
+            ui::note(to_stderr, format_args!("{} Could not look up risk level: {}", "⚠".warning(), e));
+            "unknown".to_string()
+        }
+    };
 
-    match api::client::get_skill_log(&config.api_url, skill.as_deref(), limit).await {
-        Ok(entries) => {
+    if risk_level.eq_ignore_ascii_case("safe") {
+        return Ok(true);
+    }
+
+    let summary = serde_json::from_str::<serde_json::Value>(params)
+        .map(|v| v.to_string())
+        .unwrap_or_else(|_| params.to_string());
+
+    ui::note(to_stderr, format_args!("{} {} is marked [{}]", "⚠".warning(), skill.bold(), risk_badge(&risk_level)));
+    ui::note(to_stderr, format_args!("  Params: {}", summary.dimmed()));
+
+    ui::confirm(&format!("Invoke {}?", skill), false)
+}
+
+fn risk_badge(risk_level: &str) -> colored::ColoredString {
+    match risk_level.to_lowercase().as_str() {
+        "safe" => risk_level.success(),
+        "moderate" => risk_level.warning(),
+        _ => risk_level.normal(),
+    }
+}
+
+fn print_dry_run(skill: &str, params: &str, user_email: &str, config: &Config, quiet: bool) -> Result<()> {
+    ui::header(&format!("Dry Run: {}", skill), quiet);
+
+    match api::client::build_skill_request(skill, params, Some(user_email)) {
+        Ok(body) => {
+            println!("URL:        {}", api::client::skill_url(&config.api_url));
+            println!("User:       {}", body["user_email"].as_str().unwrap_or_default());
+            println!("Session ID: {}", body["session_id"].as_str().unwrap_or_default());
+            println!("\n{}", "Params:".bold());
+            println!("{}", serde_json::to_string_pretty(&body["params"])?);
+            println!("\n{} Skill not invoked (dry run)", "•".primary());
+        }
+        Err(e) => {
+            ui::fail(format_args!("Invalid request: {:#}", e));
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn log(
+    skill: Option<String>,
+    limit: usize,
+    all_users: bool,
+    cursor: Option<String>,
+    format: ListFormat,
+    summary: bool,
+    config: &Config,
+    _verbose: bool,
+    quiet: bool,
+) -> Result<()> {
+    if format == ListFormat::Ndjson {
+        return ui::stream_ndjson(cursor, |cursor| {
+            let skill = skill.clone();
+            async move {
+                api::client::get_skill_log(&config.api_url, skill.as_deref(), limit, all_users, cursor.as_deref()).await
+            }
+        })
+        .await;
+    }
+    if format == ListFormat::Tsv {
+        return ui::stream_tsv(
+            &LOG_TSV_HEADERS,
+            cursor,
+            |cursor| {
+                let skill = skill.clone();
+                async move {
+                    api::client::get_skill_log(&config.api_url, skill.as_deref(), limit, all_users, cursor.as_deref())
+                        .await
+                }
+            },
+            log_tsv_row,
+        )
+        .await;
+    }
+
+    ui::header("Skill Audit Log", quiet);
+
+    match api::client::get_skill_log(&config.api_url, skill.as_deref(), limit, all_users, cursor.as_deref()).await {
+        Ok(page) => {
+            let entries = page.items;
             if entries.is_empty() {
-                println!("{}", "No log entries found.".yellow());
+                ui::empty(ui::Empty::SkillLog, quiet);
+            } else if summary {
+                print_log_summary(&entries);
+            } else if format == ListFormat::Table {
+                print_log_table(&entries);
+            } else if all_users {
+                print_log_by_user(&entries);
             } else {
-                for entry in &entries {
-                    let status_icon = if entry.success { "✓".green() } else { "✗".red() };
-                    println!(
-                        "{} {} {} ({}ms) - {}",
-                        status_icon,
-                        entry.skill_key.bold(),
-                        entry.user_email.dimmed(),
-                        entry.duration_ms,
-                        entry.created_at
-                    );
-                }
+                entries.iter().for_each(print_log_entry);
             }
+            ui::next_page_hint(page.next_cursor.as_deref());
         }
         Err(e) => {
-            println!("{} Failed to get skill log: {}", "✗".red(), e);
+            ui::fail_error("Failed to get skill log", &e);
         }
     }
 
     Ok(())
 }
 
+fn print_log_entry(entry: &api::client::SkillLogEntry) {
+    let status_icon = if entry.success { "✓".success() } else { "✗".error() };
+    println!(
+        "{} {} {} ({}ms) - {}",
+        status_icon,
+        entry.skill_key.bold(),
+        entry.user_email.dimmed(),
+        entry.duration_ms,
+        entry.created_at
+    );
+}
+
+/// Per-skill statistics for `skills log --summary`
+#[derive(Default)]
+struct SkillStats<'a> {
+    runs: usize,
+    succeeded: usize,
+    durations_ms: Vec<i64>,
+    last_used: &'a str,
+}
+
+/// Nearest-rank percentile of sorted values
+fn percentile(sorted: &[i64], pct: f64) -> i64 {
+    let rank = (pct / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Table of runs, success rate, p50/p95 duration, and last use per skill, busiest first
+fn print_log_summary(entries: &[api::client::SkillLogEntry]) {
+    let mut by_skill: std::collections::BTreeMap<&str, SkillStats> = std::collections::BTreeMap::new();
+    for entry in entries {
+        let stats = by_skill.entry(entry.skill_key.as_str()).or_default();
+        stats.runs += 1;
+        stats.succeeded += usize::from(entry.success);
+        stats.durations_ms.push(entry.duration_ms);
+        // ISO 8601 timestamps order correctly as strings
+        stats.last_used = stats.last_used.max(entry.created_at.as_str());
+    }
+
+    let mut rows: Vec<_> = by_skill.into_iter().collect();
+    // Stable sort keeps skills with equal counts in name order
+    rows.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.runs));
+
+    let mut table = ui::table(&["Skill", "Runs", "Success", "p50", "p95", "Last used"]);
+    for (skill, mut stats) in rows {
+        stats.durations_ms.sort_unstable();
+        ui::table_row(
+            &mut table,
+            vec![
+                skill.to_string(),
+                stats.runs.to_string(),
+                format!("{:.0}%", stats.succeeded as f64 / stats.runs as f64 * 100.0),
+                format!("{}ms", percentile(&stats.durations_ms, 50.0)),
+                format!("{}ms", percentile(&stats.durations_ms, 95.0)),
+                stats.last_used.to_string(),
+            ],
+        );
+    }
+    println!("{}", table);
+    println!("\n{} Summarized the latest {} entries (raise --limit to include more)", "•".primary(), entries.len());
+}
+
+/// Columns of `skills log --format tsv`
+const LOG_TSV_HEADERS: [&str; 5] = ["skill_key", "user_email", "success", "duration_ms", "created_at"];
+
+fn log_tsv_row(entry: &api::client::SkillLogEntry) -> Vec<String> {
+    vec![
+        entry.skill_key.clone(),
+        entry.user_email.clone(),
+        entry.success.to_string(),
+        entry.duration_ms.to_string(),
+        entry.created_at.clone(),
+    ]
+}
+
+fn print_log_table(entries: &[api::client::SkillLogEntry]) {
+    let mut table = ui::table(&["Skill", "User", "Result", "Duration", "When"]);
+    for entry in entries {
+        ui::table_row(
+            &mut table,
+            vec![
+                entry.skill_key.clone(),
+                entry.user_email.clone(),
+                if entry.success { "ok" } else { "failed" }.to_string(),
+                format!("{}ms", entry.duration_ms),
+                entry.created_at.clone(),
+            ],
+        );
+    }
+    println!("{}", table);
+}
+
+/// Poll the skill log like `tail -f`, printing entries newer than the last seen one
+async fn follow_log(
+    skill: Option<String>,
+    limit: usize,
+    all_users: bool,
+    interval: u64,
+    format: ListFormat,
+    config: &Config,
+    quiet: bool,
+) -> Result<()> {
+    let ndjson = format == ListFormat::Ndjson;
+    let tsv = format == ListFormat::Tsv;
+    if tsv {
+        println!("{}", LOG_TSV_HEADERS.join("\t"));
+    } else if !ndjson {
+        ui::header("Skill Audit Log (following, Ctrl-C to stop)", quiet);
+    }
+    ui::install_interrupt_handler();
+
+    let interval = std::time::Duration::from_secs(interval.max(1));
+    // ISO 8601 timestamps order correctly as strings. Entries have no id, so
+    // those already printed at the newest timestamp are remembered by content:
+    // more can land with the same timestamp after a poll.
+    let mut newest: Option<String> = None;
+    let mut seen_at_newest: std::collections::HashSet<String> = std::collections::HashSet::new();
+    loop {
+        match api::client::get_skill_log(&config.api_url, skill.as_deref(), limit, all_users, None).await {
+            Ok(page) => {
+                let mut fresh: Vec<_> = page
+                    .items
+                    .into_iter()
+                    .filter(|e| match &newest {
+                        None => true,
+                        Some(n) if e.created_at == *n => !seen_at_newest.contains(&log_fingerprint(e)),
+                        Some(n) => e.created_at > *n,
+                    })
+                    .collect();
+                fresh.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+                for entry in &fresh {
+                    if ndjson {
+                        println!("{}", serde_json::to_string(entry)?);
+                    } else if tsv {
+                        println!("{}", ui::tsv_row(&log_tsv_row(entry)));
+                    } else {
+                        print_log_entry(entry);
+                    }
+                }
+                if let Some(last) = fresh.last().map(|e| e.created_at.clone()) {
+                    if newest.as_ref() != Some(&last) {
+                        seen_at_newest.clear();
+                    }
+                    seen_at_newest.extend(fresh.iter().filter(|e| e.created_at == last).map(log_fingerprint));
+                    newest = Some(last);
+                }
+            }
+            Err(e) if ndjson || tsv => eprintln!("{} Failed to poll skill log: {} (retrying)", "⚠".warning(), e),
+            Err(e) => println!("{} Failed to poll skill log: {} (retrying)", "⚠".warning(), e),
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Identifies a log entry for `--follow`, which has no entry ids to go by
+fn log_fingerprint(entry: &api::client::SkillLogEntry) -> String {
+    format!("{}\t{}\t{}\t{}", entry.skill_key, entry.user_email, entry.success, entry.duration_ms)
+}
+
+/// Print log entries grouped by user, each group headed by its count and success rate
+fn print_log_by_user(entries: &[api::client::SkillLogEntry]) {
+    let mut by_user: std::collections::BTreeMap<&str, Vec<&api::client::SkillLogEntry>> =
+        std::collections::BTreeMap::new();
+    for entry in entries {
+        by_user.entry(entry.user_email.as_str()).or_default().push(entry);
+    }
+
+    for (user, entries) in &by_user {
+        let succeeded = entries.iter().filter(|e| e.success).count();
+        let rate = succeeded as f64 / entries.len() as f64 * 100.0;
+        let rate_str = format!("{:.0}% success", rate);
+        let rate_colored = if rate >= 90.0 {
+            rate_str.success()
+        } else if rate >= 50.0 {
+            rate_str.warning()
+        } else {
+            rate_str.error()
+        };
+
+        println!("\n{} {} ({} invocations, {})", "•".primary(), user.bold(), entries.len(), rate_colored);
+        for entry in entries {
+            let status_icon = if entry.success { "✓".success() } else { "✗".error() };
+            println!(
+                "    {} {} ({}ms) - {}",
+                status_icon,
+                entry.skill_key,
+                entry.duration_ms,
+                entry.created_at
+            );
+        }
+    }
+
+    println!("\n{} {} entries from {} users", "✓".success(), entries.len(), by_user.len());
+}
+
 /// Get default test parameters for each skill
 fn get_default_test_params(skill: &str) -> String {
     match skill {
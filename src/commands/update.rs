@@ -0,0 +1,113 @@
+//! Self-update from GitHub releases
+
+use anyhow::Result;
+use crate::theme::Themed;
+use self_update::backends::github::Update;
+
+use crate::ui;
+
+const REPO_OWNER: &str = "MERGE-AI-Garage";
+const REPO_NAME: &str = "pam-cli";
+
+pub async fn handle(check_only: bool, yes: bool, verbose: bool, quiet: bool) -> Result<()> {
+    ui::header("PAM Self-Update", quiet);
+
+    let current = env!("CARGO_PKG_VERSION");
+    println!("Current version: {}", current);
+    let Some(target) = release_target() else {
+        ui::fail(format_args!(
+            "Self-update isn't supported on this platform ({}-{}); build from source instead",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        ));
+        return Ok(());
+    };
+
+    if verbose {
+        println!("Release target:  {}", target);
+    }
+
+    let latest = tokio::task::spawn_blocking(move || {
+        Update::configure()
+            .repo_owner(REPO_OWNER)
+            .repo_name(REPO_NAME)
+            .bin_name("pam")
+            .target(target)
+            .current_version(current)
+            .build()?
+            .is_update_available()
+    })
+    .await?;
+
+    let release = match latest {
+        Ok(Some(release)) => release,
+        Ok(None) => {
+            println!("{} Already up to date", "✓".success());
+            return Ok(());
+        }
+        Err(e) => {
+            ui::fail(format_args!("Failed to check for updates: {}", e));
+            return Ok(());
+        }
+    };
+
+    println!("Latest version:  {}", release.version().success());
+
+    if check_only {
+        println!("\nRun {} to install it.", "pam self-update".primary());
+        return Ok(());
+    }
+
+    if !yes {
+        let confirmed = ui::confirm(
+            &format!("Update pam {} -> {}?", current, release.version()),
+            true,
+        )?;
+
+        if !confirmed {
+            println!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    let status = tokio::task::spawn_blocking(move || {
+        Update::configure()
+            .repo_owner(REPO_OWNER)
+            .repo_name(REPO_NAME)
+            .bin_name("pam")
+            .target(target)
+            .current_version(current)
+            .show_output(false)
+            .no_confirm(true)
+            .build()?
+            .update()
+    })
+    .await?;
+
+    match status {
+        Ok(status) if status.is_updated() => {
+            println!("{} Updated to {}", "✓".success(), status.version());
+        }
+        Ok(status) => {
+            println!("{} Already up to date ({})", "✓".success(), status.version());
+        }
+        Err(e) => {
+            ui::fail(format_args!("Update failed: {}", e));
+        }
+    }
+
+    Ok(())
+}
+
+/// Platform suffix used in release asset names (e.g. `pam-macos-aarch64`),
+/// or `None` where no release binary is published
+fn release_target() -> Option<&'static str> {
+    match (std::env::consts::OS, std::env::consts::ARCH) {
+        ("macos", "aarch64") => Some("macos-aarch64"),
+        ("macos", "x86_64") => Some("macos-x86_64"),
+        ("linux", "aarch64") => Some("linux-aarch64"),
+        ("linux", "x86_64") => Some("linux-x86_64"),
+        ("windows", "x86_64") => Some("windows-x86_64"),
+        _ => None,
+    }
+}
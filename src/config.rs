@@ -1,8 +1,9 @@
 //! Configuration management for PAM CLI
 
 use anyhow::{Context, Result};
+use crate::theme::Themed;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// PAM CLI Configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,12 +40,79 @@ pub struct Config {
 
     /// CLI API key for authentication (prefer env var PAM_CLI_API_KEY)
     pub cli_api_key: Option<String>,
+
+    /// HTTP request timeout in seconds (default: 60)
+    pub timeout_secs: Option<u64>,
+
+    /// How long cached API data (e.g. the skill list) stays fresh, in seconds
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+
+    /// Upper bound on parallel API requests in commands that fan out (default: 8)
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: usize,
+
+    /// Gzip large request bodies (the backend must accept Content-Encoding: gzip)
+    #[serde(default)]
+    pub compress_requests: bool,
+
+    /// Proxy for all API traffic (or env PAM_PROXY_URL); NO_PROXY still applies.
+    /// When unset, the standard HTTP_PROXY/HTTPS_PROXY env vars are used.
+    pub proxy_url: Option<String>,
+
+    /// Slack incoming webhook for `reflect --send-slack` (or env PAM_SLACK_WEBHOOK_URL)
+    pub slack_webhook_url: Option<String>,
+
+    /// Print the startup banner; unset shows it only with `--verbose`
+    pub show_banner: Option<bool>,
+
+    /// `--limit` for list and search commands when the flag isn't given
+    /// (unset: each command's built-in default)
+    pub default_limit: Option<usize>,
+
+    /// Project `jira list` uses when `--project` isn't given
+    #[serde(default = "default_jira_project")]
+    pub jira_default_project: String,
+
+    /// Where `skills invoke --archive` saves results (default: `skill_output` in the config dir)
+    pub skill_output_dir: Option<PathBuf>,
+
+    /// Output colors: a built-in theme name and per-role overrides
+    #[serde(default)]
+    pub theme: crate::theme::ThemeConfig,
+
+    /// Serve read commands from the local cache without calling the API (`--offline`)
+    #[serde(skip)]
+    pub offline: bool,
+
+    /// Record per-request timings for the `--timing` report
+    #[serde(skip)]
+    pub timing: bool,
+
+    /// Config files that were found and merged, lowest precedence first
+    #[serde(skip)]
+    pub sources: Vec<PathBuf>,
 }
 
 fn default_api_url() -> String {
     "https://pam-production-service-925072200586.us-central1.run.app".to_string()
 }
 
+/// Timeout used when neither config nor `--timeout` sets one
+pub const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
+fn default_cache_ttl_secs() -> u64 {
+    300
+}
+
+fn default_max_concurrency() -> usize {
+    8
+}
+
+fn default_jira_project() -> String {
+    "AP".to_string()
+}
+
 fn default_gcs_bucket() -> String {
     "pam-context-files".to_string()
 }
@@ -77,31 +145,86 @@ impl Default for Config {
             db_user: default_db_user(),
             db_password: None,
             cli_api_key: None,
+            timeout_secs: None,
+            cache_ttl_secs: default_cache_ttl_secs(),
+            max_concurrency: default_max_concurrency(),
+            compress_requests: false,
+            proxy_url: None,
+            slack_webhook_url: None,
+            show_banner: None,
+            default_limit: None,
+            jira_default_project: default_jira_project(),
+            skill_output_dir: None,
+            theme: Default::default(),
+            offline: false,
+            timing: false,
+            sources: Vec::new(),
         }
     }
 }
 
+/// Project-local config, looked up from the current directory upwards
+pub const PROJECT_CONFIG_FILE: &str = ".pam.toml";
+
 impl Config {
-    /// Load configuration from file or defaults
+    /// Load configuration: defaults, then the global config file, then the
+    /// nearest project `.pam.toml`, then environment variables.
+    ///
+    /// An explicit `--config` path replaces both files.
     pub fn load(config_path: Option<&str>) -> Result<Self> {
         // Load .env file if present
         let _ = dotenvy::dotenv();
 
-        // Determine config file path
-        let path = match config_path {
-            Some(p) => PathBuf::from(p),
-            None => Self::config_path()?,
+        let layers = match config_path {
+            Some(p) => vec![PathBuf::from(p)],
+            None => {
+                let mut layers = vec![Self::config_path()?];
+                layers.extend(Self::project_config_path());
+                layers
+            }
         };
+        Self::load_layers(&layers)
+    }
 
-        // Load from file if exists
-        let mut config = if path.exists() {
-            let content = std::fs::read_to_string(&path)
+    /// The nearest `.pam.toml` in the current directory or one of its parents
+    pub fn project_config_path() -> Option<PathBuf> {
+        let cwd = std::env::current_dir().ok()?;
+        cwd.ancestors()
+            .map(|dir| dir.join(PROJECT_CONFIG_FILE))
+            .find(|path| path.is_file())
+    }
+
+    /// Merge the existing files in `paths` (later files win key by key), then apply env overrides
+    fn load_layers(paths: &[PathBuf]) -> Result<Self> {
+        let mut merged = toml::Table::new();
+        let mut sources = Vec::new();
+
+        for path in paths.iter().filter(|p| p.exists()) {
+            let content = std::fs::read_to_string(path)
                 .with_context(|| format!("Failed to read config file: {}", path.display()))?;
-            toml::from_str(&content)
-                .with_context(|| format!("Failed to parse config file: {}", path.display()))?
-        } else {
-            Config::default()
-        };
+            // Parse each file as a whole config first so errors point at its line and column
+            let parsed = toml::from_str::<Config>(&content).and_then(|_| toml::from_str::<toml::Table>(&content));
+            let is_project = path.file_name().is_some_and(|name| name == PROJECT_CONFIG_FILE);
+            let table = parsed.map_err(|e| {
+                if is_project {
+                    anyhow::anyhow!("Project config {} could not be parsed:\n{}", path.display(), e)
+                } else {
+                    anyhow::anyhow!(
+                        "Config file {} could not be parsed:\n{}\n\
+                         Fix it by hand, or run `pam config init --force` to regenerate it \
+                         (the broken file is kept as config.toml.bak).",
+                        path.display(),
+                        e
+                    )
+                }
+            })?;
+            let table = if is_project { project_settings(path, table) } else { table };
+            merge_tables(&mut merged, table);
+            sources.push(path.clone());
+        }
+
+        let mut config: Config = toml::Value::Table(merged).try_into()?;
+        config.sources = sources;
 
         // Override with environment variables
         if let Ok(url) = std::env::var("PAM_API_URL") {
@@ -122,22 +245,36 @@ impl Config {
         if let Ok(password) = std::env::var("PAM_DB_PASSWORD") {
             config.db_password = Some(password);
         }
+        if let Ok(key) = std::env::var("PAM_CLI_API_KEY") {
+            config.cli_api_key = Some(key);
+        }
+        if let Ok(proxy) = std::env::var("PAM_PROXY_URL") {
+            config.proxy_url = Some(proxy);
+        }
+        if let Ok(webhook) = std::env::var("PAM_SLACK_WEBHOOK_URL") {
+            config.slack_webhook_url = Some(webhook);
+        }
 
         Ok(config)
     }
 
-    /// Get the default config file path
-    pub fn config_path() -> Result<PathBuf> {
+    /// Get the PAM config directory, creating it if needed
+    pub fn config_dir() -> Result<PathBuf> {
         let config_dir = dirs::config_dir()
             .context("Could not determine config directory")?
             .join("pam");
 
         std::fs::create_dir_all(&config_dir)?;
-        Ok(config_dir.join("config.toml"))
+        Ok(config_dir)
     }
 
-    /// Initialize a new config file
-    pub fn init(force: bool) -> Result<()> {
+    /// Get the default config file path
+    pub fn config_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("config.toml"))
+    }
+
+    /// Initialize a new config file, optionally prompting for each setting
+    pub fn init(force: bool, interactive: bool) -> Result<()> {
         let path = Self::config_path()?;
 
         if path.exists() && !force {
@@ -147,8 +284,18 @@ impl Config {
             );
         }
 
-        let default_config = Config::default();
-        let content = toml::to_string_pretty(&default_config)?;
+        if path.exists() {
+            let backup = path.with_extension("toml.bak");
+            std::fs::copy(&path, &backup)
+                .with_context(|| format!("Failed to back up {}", path.display()))?;
+            println!("Backed up existing config to: {}", backup.display());
+        }
+
+        let content = if interactive {
+            toml::to_string_pretty(&Self::prompt_settings(&path)?)?
+        } else {
+            toml::to_string_pretty(&Config::default())?
+        };
 
         std::fs::write(&path, content)?;
         println!("Created config file at: {}", path.display());
@@ -156,10 +303,74 @@ impl Config {
         Ok(())
     }
 
-    /// Set a configuration value
+    /// Walk through the main settings, pre-filled from the config file at `path`.
+    ///
+    /// Returns the file's table with the answers applied; env overrides are
+    /// never read, so they can't end up written to disk.
+    fn prompt_settings(path: &Path) -> Result<toml::Table> {
+        use dialoguer::{Confirm, Input, Password};
+
+        // A broken file is being regenerated, so start over from the defaults
+        let existing = read_table(path)
+            .ok()
+            .filter(|table| toml::Value::Table(table.clone()).try_into::<Config>().is_ok())
+            .unwrap_or_default();
+        let mut table = match toml::Value::try_from(Config::default())? {
+            toml::Value::Table(table) => table,
+            _ => unreachable!("Config serializes to a table"),
+        };
+        merge_tables(&mut table, existing);
+        let current: Config = toml::Value::Table(table.clone()).try_into()?;
+
+        let api_url: String = Input::new()
+            .with_prompt("API URL")
+            .default(current.api_url)
+            .validate_with(|input: &String| validate_url(input))
+            .interact_text()?;
+        table.insert("api_url".to_string(), api_url.into());
+
+        let email: String = Input::new()
+            .with_prompt("User email (blank to skip)")
+            .default(current.user_email.unwrap_or_default())
+            .allow_empty(true)
+            .validate_with(|input: &String| {
+                if input.is_empty() { Ok(()) } else { validate_email(input) }
+            })
+            .interact_text()?;
+        if email.is_empty() {
+            table.remove("user_email");
+        } else {
+            table.insert("user_email".to_string(), email.into());
+        }
+
+        let gcs_bucket: String = Input::new()
+            .with_prompt("GCS bucket")
+            .default(current.gcs_bucket)
+            .interact_text()?;
+        table.insert("gcs_bucket".to_string(), gcs_bucket.into());
+
+        let store_key = Confirm::new()
+            .with_prompt("Store a CLI API key in the config file? (PAM_CLI_API_KEY is safer)")
+            .default(current.cli_api_key.is_some())
+            .interact()?;
+        if store_key {
+            let key = Password::new().with_prompt("CLI API key").interact()?;
+            table.insert("cli_api_key".to_string(), key.into());
+        } else {
+            table.remove("cli_api_key");
+        }
+
+        Ok(table)
+    }
+
+    /// Set a configuration value in the global config file.
+    ///
+    /// Only `key` is written; other keys in the file are left as they are, and
+    /// defaults, env overrides, and project settings are never copied into it.
     pub fn set_value(key: &str, value: &str) -> Result<()> {
         let path = Self::config_path()?;
-        let mut config = Self::load(None)?;
+        let mut table = if path.exists() { read_table(&path)? } else { toml::Table::new() };
+        let mut config: Config = toml::Value::Table(table.clone()).try_into()?;
 
         match key {
             "api_url" => config.api_url = value.to_string(),
@@ -169,15 +380,167 @@ impl Config {
             "db_port" => config.db_port = value.parse()?,
             "db_name" => config.db_name = value.to_string(),
             "db_user" => config.db_user = value.to_string(),
+            "timeout_secs" => match value.parse()? {
+                0 => anyhow::bail!("timeout_secs must be at least 1"),
+                secs => config.timeout_secs = Some(secs),
+            },
+            "cache_ttl_secs" => config.cache_ttl_secs = value.parse()?,
+            "max_concurrency" => config.max_concurrency = value.parse()?,
+            "compress_requests" => config.compress_requests = value.parse()?,
+            "slack_webhook_url" => config.slack_webhook_url = Some(value.to_string()),
+            "proxy_url" => config.proxy_url = Some(value.to_string()),
+            "show_banner" => config.show_banner = Some(value.parse()?),
+            "default_limit" => match value.parse()? {
+                0 => anyhow::bail!("default_limit must be at least 1"),
+                n => config.default_limit = Some(n),
+            },
+            "jira_default_project" => config.jira_default_project = value.to_uppercase(),
+            "skill_output_dir" => config.skill_output_dir = Some(PathBuf::from(value)),
+            "theme" | "theme.name" => config.theme.name = Some(value.to_string()),
+            "theme.primary" => config.theme.primary = Some(value.to_string()),
+            "theme.success" => config.theme.success = Some(value.to_string()),
+            "theme.warning" => config.theme.warning = Some(value.to_string()),
+            "theme.error" => config.theme.error = Some(value.to_string()),
             _ => anyhow::bail!("Unknown config key: {}", key),
         }
 
-        let content = toml::to_string_pretty(&config)?;
-        std::fs::write(&path, content)?;
+        // Copy back just the parsed value of `key`
+        let updated = toml::Value::try_from(&config)?;
+        let dotted = if key == "theme" { "theme.name" } else { key };
+        let mut layer = toml::Table::new();
+        match dotted.split_once('.') {
+            Some((section, field)) => {
+                let value = updated[section][field].clone();
+                layer.insert(section.to_string(), toml::Value::Table(toml::Table::from_iter([(field.to_string(), value)])));
+            }
+            None => {
+                layer.insert(dotted.to_string(), updated[dotted].clone());
+            }
+        }
+        merge_tables(&mut table, layer);
+
+        std::fs::write(&path, toml::to_string_pretty(&table)?)?;
 
         Ok(())
     }
 
+    /// Write the global config file to `path` for sharing, optionally without secrets.
+    ///
+    /// Only keys set in the file are exported (no defaults, env, or project
+    /// settings), so importing it elsewhere leaves other settings alone.
+    pub fn export(path: &Path, include_secrets: bool) -> Result<()> {
+        let source = Self::config_path()?;
+        if !source.exists() {
+            anyhow::bail!("No config file at {}; run `pam config init` first", source.display());
+        }
+        let mut table = read_table(&source)?;
+
+        if !include_secrets {
+            for key in SECRET_KEYS {
+                table.remove(key);
+            }
+            // A proxy URL is only secret when it carries credentials
+            let proxy_has_password = table
+                .get("proxy_url")
+                .and_then(|v| v.as_str())
+                .and_then(|url| reqwest::Url::parse(url).ok())
+                .is_some_and(|url| url.password().is_some());
+            if proxy_has_password {
+                table.remove("proxy_url");
+            }
+        }
+
+        std::fs::write(path, toml::to_string_pretty(&table)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    /// Merge the config file at `path` into the global config, returning the keys it changed.
+    ///
+    /// The file must parse as a config and pass the same checks as `config init`;
+    /// the previous global file is kept as config.toml.bak.
+    pub fn import(path: &Path) -> Result<Vec<ChangedKey>> {
+        let layer = read_table(path)?;
+        let incoming: Config = toml::Value::Table(layer.clone())
+            .try_into()
+            .map_err(|e| anyhow::anyhow!("{} is not a valid PAM config: {}", path.display(), e))?;
+        if layer.contains_key("api_url") {
+            validate_url(&incoming.api_url).map_err(|e| anyhow::anyhow!("api_url: {}", e))?;
+        }
+        if let Some(email) = &incoming.user_email {
+            validate_email(email).map_err(|e| anyhow::anyhow!("user_email: {}", e))?;
+        }
+
+        let target = Self::config_path()?;
+        let mut merged = if target.exists() { read_table(&target)? } else { toml::Table::new() };
+        let mut changed = Vec::new();
+        changed_keys("", &merged, &layer, &mut changed);
+        if changed.is_empty() {
+            return Ok(changed);
+        }
+
+        merge_tables(&mut merged, layer);
+        // Catch combinations that only break once merged
+        toml::Value::Table(merged.clone()).try_into::<Config>()?;
+
+        if target.exists() {
+            std::fs::copy(&target, target.with_extension("toml.bak"))
+                .with_context(|| format!("Failed to back up {}", target.display()))?;
+        }
+        std::fs::write(&target, toml::to_string_pretty(&merged)?)?;
+        Ok(changed)
+    }
+
+    /// Resolve the user to act as: explicit `--user`, then config/`PAM_USER_EMAIL`,
+    /// then `git config user.email`
+    pub fn resolve_user_email(&self, user: Option<String>) -> Option<String> {
+        user.or_else(|| self.user_email.clone()).or_else(git_user_email)
+    }
+
+    /// The user email commands will default to, and where it came from
+    pub fn user_email_source(&self) -> Option<(String, &'static str)> {
+        match &self.user_email {
+            Some(email) if std::env::var("PAM_USER_EMAIL").is_ok() => Some((email.clone(), "PAM_USER_EMAIL")),
+            Some(email) => Some((email.clone(), "config")),
+            None => git_user_email().map(|email| (email, "git config user.email")),
+        }
+    }
+
+    /// A copy safe to print, with passwords, keys, and webhooks masked
+    pub fn masked(&self) -> Self {
+        let mask = |secret: &Option<String>| secret.as_ref().map(|_| "********".to_string());
+        Self {
+            db_password: mask(&self.db_password),
+            cli_api_key: mask(&self.cli_api_key),
+            slack_webhook_url: mask(&self.slack_webhook_url),
+            proxy_url: self.proxy_url.as_deref().map(crate::api::client::redact_url),
+            ..self.clone()
+        }
+    }
+
+    /// Effective HTTP request timeout
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.timeout_secs.filter(|s| *s > 0).unwrap_or(DEFAULT_TIMEOUT_SECS))
+    }
+
+    /// Directory for archived skill results
+    pub fn skill_output_dir(&self) -> Result<PathBuf> {
+        match &self.skill_output_dir {
+            Some(dir) => Ok(dir.clone()),
+            None => Ok(Self::config_dir()?.join("skill_output")),
+        }
+    }
+
+    /// Resolve a `--limit`: the flag, then `default_limit`, then the command's `builtin`
+    pub fn limit(&self, flag: Option<usize>, builtin: usize) -> usize {
+        flag.or(self.default_limit.filter(|n| *n > 0)).unwrap_or(builtin)
+    }
+
+    /// Effective fan-out limit, never below one request at a time
+    pub fn concurrency(&self) -> usize {
+        self.max_concurrency.max(1)
+    }
+
     /// Get database connection string
     pub fn db_connection_string(&self) -> String {
         let password = self
@@ -192,3 +555,113 @@ impl Config {
         )
     }
 }
+
+fn validate_url(input: &str) -> std::result::Result<(), String> {
+    match reqwest::Url::parse(input) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => Ok(()),
+        Ok(_) => Err("URL must be http(s) with a host".to_string()),
+        Err(e) => Err(format!("Invalid URL: {}", e)),
+    }
+}
+
+fn validate_email(input: &str) -> std::result::Result<(), String> {
+    match input.split_once('@') {
+        Some((local, domain)) if !local.is_empty() && domain.contains('.') && !domain.contains('@') => Ok(()),
+        _ => Err("Expected an email like name@mergeworld.com".to_string()),
+    }
+}
+
+/// Settings a project `.pam.toml` may change. Endpoints and credentials stay
+/// global-only, so a file committed to a repo can't send the API key or DB
+/// password to a host of its choosing.
+const PROJECT_KEYS: [&str; 8] = [
+    "jira_default_project",
+    "default_limit",
+    "theme",
+    "show_banner",
+    "timeout_secs",
+    "cache_ttl_secs",
+    "max_concurrency",
+    "compress_requests",
+];
+
+/// Drop (with a warning) any keys in a project config that it isn't allowed to set
+fn project_settings(path: &Path, mut table: toml::Table) -> toml::Table {
+    let ignored: Vec<String> = table.keys().filter(|key| !PROJECT_KEYS.contains(&key.as_str())).cloned().collect();
+    if !ignored.is_empty() {
+        for key in &ignored {
+            table.remove(key);
+        }
+        eprintln!(
+            "{} Ignoring {} in {}: project config can't set endpoints or credentials",
+            "⚠".warning(),
+            ignored.join(", "),
+            path.display()
+        );
+    }
+    table
+}
+
+/// Keys holding credentials, left out by `config export --no-secrets`
+const SECRET_KEYS: [&str; 3] = ["db_password", "cli_api_key", "slack_webhook_url"];
+
+/// A setting `config import` changed, with values rendered as TOML
+pub struct ChangedKey {
+    /// Dotted key, e.g. `theme.primary`
+    pub key: String,
+    /// Previous value, `None` when it was unset
+    pub old: Option<String>,
+    pub new: String,
+}
+
+impl ChangedKey {
+    /// Whether the values should be masked when shown
+    pub fn is_secret(&self) -> bool {
+        SECRET_KEYS.contains(&self.key.as_str()) || self.key == "proxy_url"
+    }
+}
+
+/// Read a TOML file as a table, with the file named in any error
+fn read_table(path: &Path) -> Result<toml::Table> {
+    let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    toml::from_str(&content).map_err(|e| anyhow::anyhow!("{} could not be parsed:\n{}", path.display(), e))
+}
+
+/// Collect the keys where overlaying `layer` onto `base` would change a value
+fn changed_keys(prefix: &str, base: &toml::Table, layer: &toml::Table, changed: &mut Vec<ChangedKey>) {
+    for (key, value) in layer {
+        let dotted = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+        match (base.get(key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(layer)) => changed_keys(&dotted, base, layer, changed),
+            (None, toml::Value::Table(layer)) => changed_keys(&dotted, &toml::Table::new(), layer, changed),
+            (old, new) if old != Some(new) => changed.push(ChangedKey {
+                key: dotted,
+                old: old.map(toml::Value::to_string),
+                new: new.to_string(),
+            }),
+            _ => {}
+        }
+    }
+}
+
+/// Overlay `layer` onto `base`, merging nested tables (like `[theme]`) key by key
+fn merge_tables(base: &mut toml::Table, layer: toml::Table) {
+    for (key, value) in layer {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(layer)) => merge_tables(base, layer),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// `git config user.email`, if git is installed and the value is set
+fn git_user_email() -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["config", "user.email"])
+        .output()
+        .ok()?;
+    let email = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !email.is_empty()).then_some(email)
+}
@@ -0,0 +1,269 @@
+//! Full-screen JSON explorer for browsing large skill results
+
+use std::collections::HashSet;
+
+use anyhow::Result;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use serde_json::Value;
+
+use crate::ui;
+
+/// Rows moved by PageUp/PageDown
+const PAGE_ROWS: usize = 20;
+
+/// Longest scalar shown inline before it's cut with "…"
+const SCALAR_CHARS: usize = 120;
+
+/// One visible line of the tree
+struct Row {
+    /// `--select`-style path to this node (e.g. `data.items[0].title`)
+    path: String,
+    depth: usize,
+    label: String,
+    /// Number of children, or `None` for scalars
+    children: Option<usize>,
+    is_array: bool,
+    /// Compact JSON for scalars; empty for objects and arrays
+    value: String,
+}
+
+struct Explorer<'a> {
+    root: &'a Value,
+    /// Path of `root` within the full result (empty unless `--select` was used)
+    base: &'a str,
+    expanded: HashSet<String>,
+    rows: Vec<Row>,
+    state: ListState,
+}
+
+impl<'a> Explorer<'a> {
+    fn new(root: &'a Value, base: &'a str) -> Self {
+        let mut explorer = Explorer {
+            root,
+            base,
+            expanded: HashSet::from([base.to_string()]),
+            rows: Vec::new(),
+            state: ListState::default().with_selected(Some(0)),
+        };
+        explorer.rebuild();
+        explorer
+    }
+
+    fn rebuild(&mut self) {
+        self.rows.clear();
+        push_rows(self.root, self.base.to_string(), 0, "(root)".to_string(), &self.expanded, &mut self.rows);
+        let last = self.rows.len().saturating_sub(1);
+        self.state.select(Some(self.selected().min(last)));
+    }
+
+    fn selected(&self) -> usize {
+        self.state.selected().unwrap_or(0)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.state.select(Some(index.min(self.rows.len().saturating_sub(1))));
+    }
+
+    fn current(&self) -> &Row {
+        &self.rows[self.selected()]
+    }
+
+    /// Expand the selected node, or step into it if it's already open
+    fn expand(&mut self) {
+        let row = self.current();
+        if row.children.is_none_or(|n| n == 0) {
+            return;
+        }
+        if self.expanded.insert(row.path.clone()) {
+            self.rebuild();
+        } else {
+            self.select(self.selected() + 1);
+        }
+    }
+
+    /// Collapse the selected node, or jump to its parent if it's already closed
+    fn collapse(&mut self) {
+        let path = self.current().path.clone();
+        if path != self.base && self.expanded.remove(&path) {
+            self.rebuild();
+            return;
+        }
+        let depth = self.current().depth;
+        if let Some(parent) = self.rows[..self.selected()].iter().rposition(|r| r.depth < depth) {
+            self.select(parent);
+        }
+    }
+
+    fn toggle(&mut self) {
+        if self.expanded.contains(&self.current().path) {
+            self.collapse();
+        } else {
+            self.expand();
+        }
+    }
+}
+
+/// Browse `value` as a collapsible tree until the user quits.
+///
+/// `base` is the `--select` path `value` came from, so the paths shown
+/// for each node can be pasted straight back into `--select`. Only the
+/// root starts expanded, so deep results open instantly.
+pub fn explore(value: &Value, base: &str) -> Result<()> {
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, value, base);
+    ratatui::restore();
+
+    // Raw mode turns Ctrl-C into a key press, so exit with the usual code ourselves
+    if let Ok(true) = result {
+        std::process::exit(ui::INTERRUPTED_EXIT_CODE);
+    }
+
+    result.map(|_| ())
+}
+
+/// Returns `true` if the user pressed Ctrl-C
+fn run(terminal: &mut DefaultTerminal, value: &Value, base: &str) -> Result<bool> {
+    let mut explorer = Explorer::new(value, base);
+
+    loop {
+        terminal.draw(|frame| draw(frame, &mut explorer))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let selected = explorer.selected();
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Down | KeyCode::Char('j') => explorer.select(selected + 1),
+            KeyCode::Up | KeyCode::Char('k') => explorer.select(selected.saturating_sub(1)),
+            KeyCode::PageDown => explorer.select(selected + PAGE_ROWS),
+            KeyCode::PageUp => explorer.select(selected.saturating_sub(PAGE_ROWS)),
+            KeyCode::Home | KeyCode::Char('g') => explorer.select(0),
+            KeyCode::End | KeyCode::Char('G') => explorer.select(usize::MAX),
+            KeyCode::Right | KeyCode::Char('l') => explorer.expand(),
+            KeyCode::Left | KeyCode::Char('h') => explorer.collapse(),
+            KeyCode::Enter | KeyCode::Char(' ') => explorer.toggle(),
+            _ => {}
+        }
+    }
+}
+
+/// Append `value` and, if it's expanded, its visible descendants
+fn push_rows(
+    value: &Value,
+    path: String,
+    depth: usize,
+    label: String,
+    expanded: &HashSet<String>,
+    rows: &mut Vec<Row>,
+) {
+    let (children, is_array, scalar) = match value {
+        Value::Object(map) => (Some(map.len()), false, String::new()),
+        Value::Array(items) => (Some(items.len()), true, String::new()),
+        _ => (None, false, ui::truncate_display(&value.to_string(), SCALAR_CHARS)),
+    };
+    let open = expanded.contains(&path);
+    rows.push(Row {
+        path: path.clone(),
+        depth,
+        label,
+        children,
+        is_array,
+        value: scalar,
+    });
+    if !open {
+        return;
+    }
+
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+                push_rows(child, child_path, depth + 1, key.clone(), expanded, rows);
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                push_rows(child, format!("{}[{}]", path, i), depth + 1, format!("[{}]", i), expanded, rows);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn draw(frame: &mut Frame, explorer: &mut Explorer) {
+    let [body, status, footer] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let items: Vec<ListItem> = explorer
+        .rows
+        .iter()
+        .map(|row| ListItem::new(row_line(row, explorer.expanded.contains(&row.path))))
+        .collect();
+    let list = List::new(items)
+        .block(Block::default().title(" Result ").borders(Borders::ALL))
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD));
+    frame.render_stateful_widget(list, body, &mut explorer.state);
+
+    let path = match explorer.current().path.as_str() {
+        "" => "(root)",
+        path => path,
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(vec![
+            Span::styled("--select ", Style::default().fg(Color::DarkGray)),
+            Span::styled(path.to_string(), Style::default().fg(Color::Cyan)),
+        ])),
+        status,
+    );
+    frame.render_widget(
+        Paragraph::new("q quit  ↑↓ move  →/← expand/collapse  enter toggle  g/G top/bottom")
+            .style(Style::default().fg(Color::DarkGray)),
+        footer,
+    );
+}
+
+fn row_line(row: &Row, open: bool) -> Line<'static> {
+    let indent = "  ".repeat(row.depth);
+    let label = Span::styled(row.label.clone(), Style::default().add_modifier(Modifier::BOLD));
+
+    match row.children {
+        Some(count) => {
+            let marker = match (count, open) {
+                (0, _) => "  ",
+                (_, true) => "▾ ",
+                (_, false) => "▸ ",
+            };
+            let summary = match (row.is_array, count) {
+                (true, 1) => "[1 item]".to_string(),
+                (true, n) => format!("[{} items]", n),
+                (false, 1) => "{1 key}".to_string(),
+                (false, n) => format!("{{{} keys}}", n),
+            };
+            Line::from(vec![
+                Span::raw(format!("{}{}", indent, marker)),
+                label,
+                Span::styled(format!(" {}", summary), Style::default().fg(Color::DarkGray)),
+            ])
+        }
+        None => Line::from(vec![
+            Span::raw(format!("{}  ", indent)),
+            label,
+            Span::raw(": "),
+            Span::styled(row.value.clone(), Style::default().fg(Color::Green)),
+        ]),
+    }
+}
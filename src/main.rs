@@ -4,14 +4,19 @@
 //! Follows Maestro's CLI-first pattern: every capability testable from terminal.
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
 use colored::Colorize;
+use theme::Themed;
 
 mod commands;
 mod api;
+mod cache;
 mod config;
+mod explorer;
+mod theme;
+mod ui;
 
-use commands::{memory, skills, context, reflect, chat, jira};
+use commands::{memory, skills, context, reflect, chat, jira, dashboard, update, raw};
 
 /// PAM - Proactive Agentic Manager CLI
 ///
@@ -19,7 +24,7 @@ use commands::{memory, skills, context, reflect, chat, jira};
 #[derive(Parser)]
 #[command(name = "pam")]
 #[command(author = "AI Garage <ai-garage@mergeworld.com>")]
-#[command(version = "0.1.0")]
+#[command(version)]
 #[command(about = "PAM Chief of Staff CLI - Your AI-powered PM assistant", long_about = None)]
 #[command(propagate_version = true)]
 struct Cli {
@@ -27,12 +32,52 @@ struct Cli {
     #[arg(short, long, global = true)]
     verbose: bool,
 
+    /// Suppress decorative output (headers, banners, progress lines)
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
     /// Configuration file path
     #[arg(short, long, global = true, env = "PAM_CONFIG")]
     config: Option<String>,
 
+    /// HTTP timeout in seconds for this invocation (overrides config)
+    #[arg(long, global = true, value_name = "SECS", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+
+    /// Maximum parallel API requests for commands that fan out (overrides config)
+    #[arg(long, global = true, value_name = "N")]
+    max_concurrency: Option<usize>,
+
+    /// Print the startup banner (default: only with --verbose, or `show_banner` in config)
+    #[arg(long, global = true, conflicts_with_all = ["no_banner", "quiet"])]
+    banner: bool,
+
+    /// Never print the startup banner, even with --verbose
+    #[arg(long, global = true)]
+    no_banner: bool,
+
+    /// Disable colored output (also honors the NO_COLOR env var)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Exit with code 1 when the command reports a failure (default: exit 0 after printing ✗)
+    #[arg(long, global = true)]
+    strict: bool,
+
+    /// Serve read-only commands from the local cache instead of the API
+    #[arg(long, global = true)]
+    offline: bool,
+
+    /// After the command, print DNS, first-byte, and total timings to stderr
+    #[arg(long, global = true)]
+    timing: bool,
+
+    /// Print the effective config for this invocation (secrets masked) and exit
+    #[arg(long, global = true)]
+    show_config: bool,
+
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
@@ -51,6 +96,10 @@ enum Commands {
 
     /// Context - manage context bundles from GCS
     Context {
+        /// Whose context bundle to use (default: your configured email)
+        #[arg(short, long, global = true, env = "PAM_USER_EMAIL")]
+        user: Option<String>,
+
         #[command(subcommand)]
         action: ContextAction,
     },
@@ -68,10 +117,42 @@ enum Commands {
         /// User email to reflect for
         #[arg(short, long, env = "PAM_USER_EMAIL")]
         user: Option<String>,
+
+        /// Post the summary to the configured Slack webhook
+        #[arg(long)]
+        send_slack: bool,
+
+        /// Only reflect on sessions created since the last reflection
+        #[arg(long, conflicts_with = "session")]
+        since_last: bool,
+
+        /// Print the reflection as JSON (same as --format json)
+        #[arg(long)]
+        json: bool,
+
+        /// Print the reflection as JSON or YAML (status messages go to stderr)
+        #[arg(long, value_enum, conflicts_with = "json")]
+        format: Option<ui::DataFormat>,
+
+        /// Compare action items and learnings with a previously saved reflection
+        #[arg(long, value_name = "REFLECTION_ID", conflicts_with_all = ["json", "format"])]
+        compare: Option<String>,
+
+        /// Print only the numbered action items, e.g. to paste into a task tracker
+        #[arg(long, conflicts_with_all = ["json", "format", "compare"])]
+        action_items_only: bool,
+
+        /// Refresh the context bundle first if any file is stale
+        #[arg(long)]
+        refresh_before: bool,
     },
 
     /// Chat - interactive conversation with PAM
+    #[command(args_conflicts_with_subcommands = true)]
     Chat {
+        #[command(subcommand)]
+        action: Option<ChatAction>,
+
         /// The message to send (or omit for interactive mode)
         message: Option<String>,
 
@@ -80,8 +161,44 @@ enum Commands {
         user: Option<String>,
 
         /// Continue previous session
-        #[arg(short, long)]
+        #[arg(long)]
         continue_session: bool,
+
+        /// Continue the local session with this friendly name or id
+        #[arg(long, value_name = "NAME|ID", conflicts_with = "continue_session")]
+        session: Option<String>,
+
+        /// Use this exact session id (letters, digits, `_`, `-`, `.`), e.g. to match server logs
+        #[arg(long, value_name = "ID", conflicts_with_all = ["continue_session", "session"])]
+        session_id: Option<String>,
+
+        /// Give this session a friendly name
+        #[arg(short, long)]
+        name: Option<String>,
+
+        /// Resend a single message up to N times on network errors
+        #[arg(long, default_value = "0", value_name = "N")]
+        retries: u32,
+
+        /// Attach a file's contents to the (first) message (repeatable)
+        #[arg(short, long, value_name = "PATH")]
+        file: Vec<String>,
+
+        /// Replace PAM's system prompt for this session (backends without support ignore it)
+        #[arg(long, value_name = "PROMPT")]
+        system: Option<String>,
+
+        /// Refresh the context bundle first if any file is stale
+        #[arg(long)]
+        refresh_before: bool,
+
+        /// If the chat service is down, keep messages in a local outbox and send them later
+        #[arg(long)]
+        queue: bool,
+
+        /// Send queued messages (oldest first) and exit
+        #[arg(long, conflicts_with_all = ["message", "queue", "continue_session", "session", "session_id", "name", "file"])]
+        flush: bool,
     },
 
     /// Health - check PAM system health
@@ -89,6 +206,10 @@ enum Commands {
         /// Deep health check (probes all services)
         #[arg(short, long)]
         deep: bool,
+
+        /// Re-run the deep check every SECS seconds on one status line (Ctrl-C to stop)
+        #[arg(short, long, value_name = "SECS")]
+        watch: Option<u64>,
     },
 
     /// Config - manage PAM CLI configuration
@@ -102,6 +223,60 @@ enum Commands {
         #[command(subcommand)]
         action: JiraAction,
     },
+
+    /// Dashboard - live full-screen overview of PAM systems
+    Dashboard {
+        /// Refresh interval in seconds
+        #[arg(short, long, default_value = "5")]
+        interval: u64,
+    },
+
+    /// Api - send an authenticated request to any endpoint under api_url
+    Api {
+        /// HTTP method
+        #[arg(value_enum, ignore_case = true)]
+        method: raw::HttpMethod,
+
+        /// Path relative to api_url (e.g. /api/chief-of-staff/memory/status)
+        path: String,
+
+        /// JSON body, `@file` to read it from a file, or `-` for stdin
+        #[arg(short, long)]
+        body: Option<String>,
+
+        /// User email sent as X-User-Email
+        #[arg(short, long, env = "PAM_USER_EMAIL")]
+        user: Option<String>,
+
+        /// Retry up to N times on network errors
+        #[arg(long, default_value = "0", value_name = "N")]
+        retries: u32,
+
+        /// Print JSON responses compactly on one line
+        #[arg(long)]
+        raw: bool,
+    },
+
+    /// Version - show CLI and backend versions (add --verbose for build details)
+    Version,
+
+    /// Whoami - show which user, API key, and API URL this invocation will use
+    Whoami {
+        /// Also check the API key against the backend
+        #[arg(long)]
+        verify: bool,
+    },
+
+    /// Self-update - install the latest release from GitHub
+    SelfUpdate {
+        /// Only report whether an update is available
+        #[arg(long)]
+        check_only: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -111,6 +286,10 @@ enum MemoryAction {
         /// Deep status check (probes vector + embedding availability)
         #[arg(short, long)]
         deep: bool,
+
+        /// Query the database directly instead of the API
+        #[arg(long)]
+        direct: bool,
     },
 
     /// Search memories semantically
@@ -118,13 +297,57 @@ enum MemoryAction {
         /// The search query
         query: String,
 
-        /// Maximum results to return
-        #[arg(short, long, default_value = "10")]
-        limit: usize,
+        /// Maximum results to return (default: `default_limit` from config, else 10)
+        #[arg(short, long)]
+        limit: Option<usize>,
+
+        /// User email to search for (repeat to search several users)
+        #[arg(short, long)]
+        user: Vec<String>,
 
-        /// User email to search for
+        /// Only return memories with this tag (repeatable; all must match)
         #[arg(short, long)]
-        user: Option<String>,
+        tags: Vec<String>,
+
+        /// Match memories that have any of the --tags instead of all
+        #[arg(long, requires = "tags")]
+        any_tag: bool,
+
+        /// Order results by this field
+        #[arg(short, long, value_enum, default_value_t = memory::SearchSort::Score)]
+        sort: memory::SearchSort,
+
+        /// Sort ascending (default is descending)
+        #[arg(long, conflicts_with = "desc")]
+        asc: bool,
+
+        /// Sort descending
+        #[arg(long)]
+        desc: bool,
+
+        /// Show N lines around each match in the full memory, like grep -C
+        #[arg(short = 'C', long, value_name = "N")]
+        context: Option<usize>,
+
+        /// Open the full text of result N (as numbered in the output) in $PAGER
+        #[arg(long, value_name = "N")]
+        open: Option<usize>,
+
+        /// Show a content preview under each result (the default on a terminal)
+        #[arg(long, conflicts_with = "no_preview")]
+        include_preview: bool,
+
+        /// Never show content previews
+        #[arg(long)]
+        no_preview: bool,
+
+        /// Cut each preview to N characters (default: fitted to the terminal)
+        #[arg(long, value_name = "N", conflicts_with = "no_preview")]
+        preview_length: Option<usize>,
+
+        /// Show why each result ranked where it did (matched terms, distance, recency)
+        #[arg(long)]
+        explain: bool,
     },
 
     /// Index content into memory
@@ -132,21 +355,65 @@ enum MemoryAction {
         /// Content to index (or - for stdin)
         content: Option<String>,
 
-        /// File to index
+        /// File to index; repeat it, or pass a directory, to index each file separately
         #[arg(short, long)]
-        file: Option<String>,
+        file: Vec<String>,
+
+        /// Fetch a web page and index its readable text (repeatable)
+        #[arg(long, conflicts_with_all = ["content", "file"])]
+        from_url: Vec<String>,
 
         /// Tags for the memory
         #[arg(short, long)]
         tags: Vec<String>,
+
+        /// Skip content that is already indexed (matched by SHA-256)
+        #[arg(long)]
+        skip_duplicates: bool,
+
+        /// When indexing several files or URLs, send at most N requests per second
+        #[arg(long, value_name = "N")]
+        rate: Option<f64>,
     },
 
     /// List recent memories
     List {
-        /// Number of memories to list
-        #[arg(short, long, default_value = "20")]
-        limit: usize,
+        /// Number of memories to list (default: `default_limit` from config, else 20)
+        #[arg(short, long)]
+        limit: Option<usize>,
+
+        /// Filter by user
+        #[arg(short, long)]
+        user: Option<String>,
+
+        /// Continue from the cursor printed by a previous page
+        #[arg(long, value_name = "TOKEN")]
+        cursor: Option<String>,
+
+        /// Order results by this field
+        #[arg(short, long, value_enum, default_value_t = memory::ListSort::Date)]
+        sort: memory::ListSort,
 
+        /// Sort ascending (default is descending). Only the fetched page is reordered:
+        /// with a date sort this shows the newest N oldest-first, not the oldest N
+        #[arg(long, conflicts_with = "desc")]
+        asc: bool,
+
+        /// Sort descending
+        #[arg(long)]
+        desc: bool,
+
+        /// Output layout
+        #[arg(short, long, value_enum, default_value_t = ui::ListFormat::List)]
+        format: ui::ListFormat,
+
+        /// Cut each preview to N characters (default: the full preview, fitted to the terminal)
+        #[arg(long, value_name = "N")]
+        preview_length: Option<usize>,
+    },
+
+    /// Show memory counts by tag
+    Stats {
         /// Filter by user
         #[arg(short, long)]
         user: Option<String>,
@@ -161,6 +428,20 @@ enum MemoryAction {
         /// Skip confirmation prompt
         #[arg(short, long)]
         force: bool,
+
+        /// Show how many memories would be deleted, with a few samples, and delete nothing
+        #[arg(long, conflicts_with = "force")]
+        dry_run: bool,
+    },
+
+    /// Re-index memories from a JSON or markdown export
+    Import {
+        /// Export file (.json, or markdown with one `## ` section per memory)
+        file: String,
+
+        /// Report how many memories would be indexed without writing
+        #[arg(long)]
+        dry_run: bool,
     },
 }
 
@@ -171,6 +452,46 @@ enum SkillsAction {
         /// Show detailed information
         #[arg(short, long)]
         detailed: bool,
+
+        /// Bypass the local skill cache and fetch a fresh list
+        #[arg(short, long)]
+        refresh: bool,
+
+        /// Output layout
+        #[arg(short, long, value_enum, default_value_t = ui::ListFormat::List)]
+        format: ui::ListFormat,
+
+        /// Show only skills bookmarked with `skills fav add`
+        #[arg(long)]
+        favorites: bool,
+
+        /// Order skills by this field (favorites stay on top)
+        #[arg(short, long, value_enum, default_value_t = skills::SkillSort::Usage)]
+        sort: skills::SkillSort,
+
+        /// Show only skills with this risk level (e.g. safe, moderate)
+        #[arg(long, value_name = "LEVEL")]
+        risk: Option<String>,
+
+        /// Show only enabled skills
+        #[arg(long, conflicts_with = "disabled")]
+        enabled: bool,
+
+        /// Show only disabled skills
+        #[arg(long)]
+        disabled: bool,
+    },
+
+    /// Bookmark commonly used skills
+    Fav {
+        #[command(subcommand)]
+        action: FavAction,
+    },
+
+    /// Manage params saved with --save-default-params
+    Params {
+        #[command(subcommand)]
+        action: ParamsAction,
     },
 
     /// Test a specific skill
@@ -181,6 +502,18 @@ enum SkillsAction {
         /// Test parameters as JSON
         #[arg(short, long)]
         params: Option<String>,
+
+        /// Cut the content output after N characters
+        #[arg(long, value_name = "N", default_value = "500")]
+        truncate: usize,
+
+        /// Print the entire content output
+        #[arg(long, conflicts_with = "truncate")]
+        full: bool,
+
+        /// Save the params as this skill's default test params if the test succeeds
+        #[arg(long)]
+        save_default_params: bool,
     },
 
     /// Invoke a skill
@@ -188,13 +521,72 @@ enum SkillsAction {
         /// Skill key to invoke
         skill: String,
 
-        /// Parameters as JSON
-        #[arg(short, long)]
-        params: String,
+        /// Parameters as JSON, `@file` to read them from a file, or `-` for stdin
+        #[arg(short, long, required_unless_present_any = ["emails_file", "date", "query"])]
+        params: Option<String>,
+
+        /// Substitute `{{ENV_VAR}}` placeholders in the params from the environment
+        #[arg(long, requires = "params")]
+        template: bool,
+
+        /// Set `emails` from a file with one address per line (e.g. for freebusy)
+        #[arg(long, value_name = "PATH")]
+        emails_file: Option<std::path::PathBuf>,
+
+        /// Set `date` (YYYY-MM-DD)
+        #[arg(long)]
+        date: Option<chrono::NaiveDate>,
+
+        /// Set `query` (e.g. for jira-query, github-commits)
+        #[arg(long)]
+        query: Option<String>,
 
         /// User email for audit
         #[arg(short, long, env = "PAM_USER_EMAIL")]
         user: Option<String>,
+
+        /// Show the request that would be sent without invoking the skill
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt for non-safe skills
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Print only this part of the result (e.g. content, data.items[0].title)
+        #[arg(short, long)]
+        select: Option<String>,
+
+        /// Re-run every SECS seconds, showing the latest result (Ctrl-C to stop)
+        #[arg(short, long, value_name = "SECS", conflicts_with = "dry_run")]
+        watch: Option<u64>,
+
+        /// Also save the result, params, and user as JSON in `skill_output_dir`
+        #[arg(long, conflicts_with = "dry_run")]
+        archive: bool,
+
+        /// Retry up to N times on network errors (retries reuse the idempotency key)
+        #[arg(long, default_value = "0", value_name = "N")]
+        retries: u32,
+
+        /// Print the whole result (or the --select'ed part) as JSON or YAML, with no status line
+        #[arg(long, value_enum, conflicts_with = "dry_run")]
+        format: Option<ui::DataFormat>,
+
+        /// Browse the result (or the --select'ed part) in a full-screen JSON explorer
+        #[arg(short, long, conflicts_with_all = ["dry_run", "watch", "format"])]
+        interactive: bool,
+
+        /// Save the params as this skill's default test params if the call succeeds
+        /// (not with --template, so expanded secrets never reach disk)
+        #[arg(long, conflicts_with_all = ["dry_run", "template"])]
+        save_default_params: bool,
+    },
+
+    /// Describe a skill's parameters
+    Describe {
+        /// Skill key to describe
+        skill: String,
     },
 
     /// Show skill audit log
@@ -203,9 +595,73 @@ enum SkillsAction {
         #[arg(short, long)]
         skill: Option<String>,
 
-        /// Number of entries to show
-        #[arg(short, long, default_value = "20")]
-        limit: usize,
+        /// Number of entries to show (default: `default_limit` from config, else 20)
+        #[arg(short, long)]
+        limit: Option<usize>,
+
+        /// Show the whole team's log grouped by user, with success rates
+        #[arg(short, long)]
+        all_users: bool,
+
+        /// Continue from the cursor printed by a previous page
+        #[arg(long, value_name = "TOKEN")]
+        cursor: Option<String>,
+
+        /// Keep polling and print new entries as they arrive (Ctrl-C to stop)
+        #[arg(short, long, conflicts_with = "cursor")]
+        follow: bool,
+
+        /// Seconds between polls with --follow
+        #[arg(long, value_name = "SECS", default_value = "5", requires = "follow")]
+        interval: u64,
+
+        /// Output layout (ndjson and tsv stream every page, using --limit as the page size)
+        #[arg(short = 'F', long, value_enum, default_value_t = ui::ListFormat::List)]
+        format: ui::ListFormat,
+
+        /// Summarize the fetched entries per skill: runs, success rate, p50/p95 duration, last use
+        #[arg(long, conflicts_with_all = ["follow", "format"])]
+        summary: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum FavAction {
+    /// Add a skill to your favorites
+    Add {
+        /// Skill key to bookmark
+        skill: String,
+    },
+
+    /// Remove a skill from your favorites
+    Remove {
+        /// Skill key to unbookmark
+        skill: String,
+    },
+
+    /// List your favorite skills
+    List,
+}
+
+#[derive(Subcommand)]
+enum ParamsAction {
+    /// Show the saved default params for every skill
+    List,
+
+    /// Forget a skill's saved params, going back to the built-in defaults
+    Clear {
+        /// Skill key whose saved params to remove
+        skill: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ChatAction {
+    /// List chat sessions started from this machine
+    History {
+        /// Number of sessions to show (default: `default_limit` from config, else 20)
+        #[arg(short, long)]
+        limit: Option<usize>,
     },
 }
 
@@ -223,6 +679,18 @@ enum ContextAction {
         /// Force refresh even if fresh
         #[arg(short, long)]
         force: bool,
+
+        /// List the files that would be refreshed without refreshing them
+        #[arg(long)]
+        dry_run: bool,
+
+        /// After refreshing, wait until every file is fresh (exits 1 if they aren't by --wait-timeout)
+        #[arg(long, conflicts_with = "dry_run")]
+        watch_until_fresh: bool,
+
+        /// Give up waiting for freshness after SECS seconds
+        #[arg(long, value_name = "SECS", default_value = "300", requires = "watch_until_fresh")]
+        wait_timeout: u64,
     },
 
     /// Show specific context file
@@ -233,19 +701,62 @@ enum ContextAction {
         /// Show raw content (no formatting)
         #[arg(short, long)]
         raw: bool,
+
+        /// Ignore the locally cached copy and download the file again
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Print only the section under this heading (case-insensitive prefix match)
+        #[arg(short, long, value_name = "HEADING")]
+        section: Option<String>,
+
+        /// Re-check every SECS seconds and redraw when the file changes (Ctrl-C to stop)
+        #[arg(short, long, value_name = "SECS")]
+        watch: Option<u64>,
     },
 
-    /// List all context files
-    List,
+    /// List all context files, grouped by category
+    List {
+        /// Print `{category: [files]}` as JSON
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
+
+        /// Output layout: grouped list, table, ndjson, or tsv
+        #[arg(short, long, value_enum, default_value_t = ui::ListFormat::List)]
+        format: ui::ListFormat,
+    },
+
+    /// Download every context file into a directory, resuming past runs
+    Download {
+        /// Directory to write the files into
+        #[arg(short, long, default_value = "context")]
+        dir: std::path::PathBuf,
+
+        /// Download files that already exist locally instead of skipping them
+        #[arg(long)]
+        overwrite: bool,
+
+        /// Retry each failed file up to N times on network errors
+        #[arg(long, default_value = "2", value_name = "N")]
+        retries: u32,
+    },
 
     /// Show context bundle statistics
-    Stats,
+    Stats {
+        /// Show how bundle size has trended across recent runs
+        #[arg(long)]
+        history: bool,
+    },
 }
 
 #[derive(Subcommand)]
 enum ConfigAction {
     /// Show current configuration
-    Show,
+    Show {
+        /// Print every setting (secrets masked) as JSON or YAML
+        #[arg(short, long, value_enum)]
+        format: Option<ui::DataFormat>,
+    },
 
     /// Set a configuration value
     Set {
@@ -261,10 +772,30 @@ enum ConfigAction {
         /// Force overwrite existing config
         #[arg(short, long)]
         force: bool,
+
+        /// Prompt for each setting instead of writing defaults
+        #[arg(short, long)]
+        interactive: bool,
     },
 
     /// Show configuration file path
     Path,
+
+    /// Write the config file to PATH to share a setup (e.g. a team baseline)
+    Export {
+        /// Where to write the exported TOML
+        path: std::path::PathBuf,
+
+        /// Leave out the CLI API key, DB password, Slack webhook, and proxy credentials
+        #[arg(long)]
+        no_secrets: bool,
+    },
+
+    /// Merge a config file into yours, reporting which keys changed
+    Import {
+        /// TOML file to import (e.g. from `pam config export`)
+        path: std::path::PathBuf,
+    },
 }
 
 #[derive(Subcommand)]
@@ -294,8 +825,8 @@ pub enum JiraAction {
 
     /// List Jira tickets
     List {
-        /// Project key (default: AP)
-        #[arg(short, long, default_value = "AP")]
+        /// Project key (default: `jira_default_project` from config, AP if unset)
+        #[arg(short, long)]
         project: Option<String>,
 
         /// Filter by status
@@ -306,9 +837,9 @@ pub enum JiraAction {
         #[arg(short, long)]
         assignee: Option<String>,
 
-        /// Maximum tickets to show
-        #[arg(short, long, default_value = "20")]
-        limit: usize,
+        /// Maximum tickets to show (default: `default_limit` from config, else 20)
+        #[arg(short, long)]
+        limit: Option<usize>,
     },
 
     /// List available Jira projects
@@ -323,42 +854,224 @@ pub enum JiraAction {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize logging
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::from_default_env()
-                .add_directive(tracing::Level::INFO.into()),
-        )
-        .init();
-
     // Parse CLI arguments
     let cli = Cli::parse();
 
-    // Load configuration
-    let config = config::Config::load(cli.config.as_deref())?;
+    if cli.no_color {
+        colored::control::set_override(false);
+    }
 
-    // Print banner in verbose mode
+    // Initialize logging (--verbose bumps our own logs to DEBUG, e.g. HTTP requests)
+    let mut filter = tracing_subscriber::EnvFilter::from_default_env()
+        .add_directive(tracing::Level::INFO.into());
     if cli.verbose {
+        filter = filter.add_directive("pam=debug".parse()?);
+    }
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+
+    // Load configuration. `config init --force` must still work when the
+    // existing file is broken, since that is how users recover from it.
+    let regenerating = matches!(
+        cli.command,
+        Some(Commands::Config { action: ConfigAction::Init { force: true, .. } })
+    );
+    let mut config = match config::Config::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(_) if regenerating => config::Config::default(),
+        Err(e) => {
+            eprintln!("{} {}", "✗".error(), e);
+            std::process::exit(1);
+        }
+    };
+
+    // Apply per-invocation overrides: flag > config > built-in default
+    let timeout_source = if cli.timeout.is_some() {
+        "--timeout"
+    } else if config.timeout_secs.is_some() {
+        "config"
+    } else {
+        "default"
+    };
+    if let Some(secs) = cli.timeout {
+        config.timeout_secs = Some(secs);
+    }
+    if let Some(n) = cli.max_concurrency {
+        config.max_concurrency = n;
+    }
+    config.offline = cli.offline;
+    config.timing = cli.timing;
+    theme::set(theme::Theme::from_config(&config.theme));
+
+    if cli.show_config {
+        ui::header("Effective Configuration", cli.quiet);
+        if config.sources.is_empty() {
+            println!("# No config files found; built-in defaults and environment only");
+        }
+        for source in &config.sources {
+            println!("# From {}", source.display());
+        }
+        print!("{}", toml::to_string_pretty(&config.masked())?);
+        return Ok(());
+    }
+
+    let Some(command) = cli.command else {
+        Cli::command()
+            .error(clap::error::ErrorKind::MissingSubcommand, "a subcommand is required")
+            .exit();
+    };
+
+    api::client::configure(&config);
+
+    // Banner: flag > config > shown with --verbose (the original behavior)
+    let show_banner = if cli.banner {
+        true
+    } else if cli.no_banner {
+        false
+    } else {
+        config.show_banner.unwrap_or(cli.verbose) && !cli.quiet
+    };
+    if show_banner {
         print_banner();
     }
 
+    if cli.verbose {
+        println!("Timeout: {}s ({})", config.timeout().as_secs(), timeout_source);
+        println!("Proxy:   {}", api::client::describe_proxy(&config));
+        println!("Fan-out: {} parallel requests", config.concurrency());
+    }
+
+    let json = json_output(&command);
+    let strict = cli.strict || gates_on_success(&command);
+    if json {
+        ui::use_json_errors();
+    }
+
     // Route to appropriate command handler
-    match cli.command {
-        Commands::Memory { action } => memory::handle(action, &config, cli.verbose).await,
-        Commands::Skills { action } => skills::handle(action, &config, cli.verbose).await,
-        Commands::Context { action } => context::handle(action, &config, cli.verbose).await,
-        Commands::Reflect { session, export, user } => {
-            reflect::handle(session, export, user, &config, cli.verbose).await
+    let started = std::time::Instant::now();
+    let result = match command {
+        Commands::Memory { action } => memory::handle(action, &config, cli.verbose, cli.quiet).await,
+        Commands::Skills { action } => skills::handle(action, &config, cli.verbose, cli.quiet).await,
+        Commands::Context { user, action } => context::handle(action, user, &config, cli.verbose, cli.quiet).await,
+        Commands::Reflect {
+            session,
+            export,
+            user,
+            send_slack,
+            since_last,
+            json,
+            format,
+            compare,
+            action_items_only,
+            refresh_before,
+        } => {
+            let format = format.or(json.then_some(ui::DataFormat::Json));
+            let options = reflect::ReflectOptions {
+                export,
+                send_slack,
+                since_last,
+                format,
+                compare,
+                action_items_only,
+                refresh_before,
+            };
+            reflect::handle(session, user, options, &config, cli.verbose, cli.quiet).await
+        }
+        Commands::Chat { action: Some(action), .. } => chat::handle_action(action, &config, cli.quiet),
+        Commands::Chat {
+            action: None,
+            message,
+            user,
+            continue_session,
+            session,
+            session_id,
+            name,
+            retries,
+            file,
+            system,
+            refresh_before,
+            queue,
+            flush,
+        } => {
+            let options = chat::ChatOptions {
+                continue_session,
+                session,
+                session_id,
+                name,
+                retries,
+                files: file,
+                system,
+                refresh_before,
+                queue,
+                flush,
+            };
+            chat::handle(message, user, options, &config, cli.verbose, cli.quiet).await
+        }
+        Commands::Health { watch: Some(secs), .. } => watch_health(secs, &config, cli.quiet).await,
+        Commands::Health { deep, watch: None } => health_check(deep, &config, cli.quiet).await,
+        Commands::Config { action } => handle_config(action, &config, cli.quiet),
+        Commands::Jira { action } => jira::handle(action, &config, cli.verbose, cli.quiet).await,
+        Commands::Dashboard { interval } => dashboard::handle(interval, &config, cli.verbose).await,
+        Commands::Api { method, path, body, user, retries, raw } => {
+            raw::handle(method, &path, body.as_deref(), user, retries, raw, &config, cli.quiet).await
+        }
+        Commands::Version => print_version(&config, cli.verbose).await,
+        Commands::Whoami { verify } => whoami(verify, &config, cli.config.as_deref(), cli.quiet).await,
+        Commands::SelfUpdate { check_only, yes } => {
+            update::handle(check_only, yes, cli.verbose, cli.quiet).await
         }
-        Commands::Chat { message, user, continue_session } => {
-            chat::handle(message, user, continue_session, &config, cli.verbose).await
+    };
+
+    if cli.timing {
+        api::timing::report(started.elapsed());
+    }
+
+    // JSON consumers get errors as JSON on stdout and always a non-zero exit
+    if json {
+        if let Err(e) = &result {
+            ui::fail_error("Error", e);
+        }
+        if ui::failed() {
+            std::process::exit(1);
+        }
+    }
+
+    // Handlers print failures and carry on; --strict makes them count for scripts
+    if result.is_ok() && strict && ui::failed() {
+        std::process::exit(1);
+    }
+    result
+}
+
+/// Whether the command was asked for JSON (`--json`, `--format json`, or
+/// `--format ndjson`), in which case failures are reported as JSON too
+fn json_output(command: &Commands) -> bool {
+    use ui::{DataFormat, ListFormat};
+
+    match command {
+        Commands::Memory { action: MemoryAction::List { format, .. } } => *format == ListFormat::Ndjson,
+        Commands::Skills { action: SkillsAction::List { format, .. } | SkillsAction::Log { format, .. } } => {
+            *format == ListFormat::Ndjson
         }
-        Commands::Health { deep } => health_check(deep, &config).await,
-        Commands::Config { action } => handle_config(action, &config),
-        Commands::Jira { action } => jira::handle(action, &config, cli.verbose).await,
+        Commands::Skills { action: SkillsAction::Invoke { format, .. } } => *format == Some(DataFormat::Json),
+        Commands::Context { action: ContextAction::List { json, format }, .. } => *json || *format == ListFormat::Ndjson,
+        Commands::Reflect { json, format, .. } => *json || *format == Some(DataFormat::Json),
+        Commands::Config { action: ConfigAction::Show { format } } => *format == Some(DataFormat::Json),
+        _ => false,
     }
 }
 
+/// Whether the command exists to gate a script, so any failure exits non-zero
+/// even without `--strict`
+fn gates_on_success(command: &Commands) -> bool {
+    matches!(
+        command,
+        Commands::Context { action: ContextAction::Refresh { watch_until_fresh: true, .. }, .. }
+    )
+}
+
 fn print_banner() {
     println!("{}", "╔════════════════════════════════════════════════════════════╗".bright_cyan());
     println!("{}", "║  PAM - Proactive Agentic Manager                           ║".bright_cyan());
@@ -367,66 +1080,261 @@ fn print_banner() {
     println!();
 }
 
-async fn health_check(deep: bool, config: &config::Config) -> Result<()> {
-    println!("{}", "PAM Health Check".bold());
-    println!("{}", "─".repeat(40));
+async fn health_check(deep: bool, config: &config::Config, quiet: bool) -> Result<()> {
+    ui::header("PAM Health Check", quiet);
 
     // Basic health
-    println!("{} API Endpoint: {}", "•".green(), config.api_url);
+    println!("{} API Endpoint: {}", "•".success(), config.api_url);
 
     if deep {
         println!("\n{}", "Deep Health Check".bold());
 
         // Check API
         print!("  Checking API... ");
-        match api::client::health_check(&config.api_url).await {
-            Ok(status) => println!("{} {}", "✓".green(), status),
-            Err(e) => println!("{} {}", "✗".red(), e),
-        }
+        let api_up = match api::client::health_check(&config.api_url).await {
+            Ok(health) if health.status == "Healthy" => {
+                println!("{} {}", "✓".success(), health.status);
+                true
+            }
+            Ok(health) => {
+                ui::fail(format_args!("API reports status: {}", health.status));
+                false
+            }
+            Err(e) => {
+                ui::fail(e);
+                false
+            }
+        };
 
         // Check Database
         print!("  Checking Database... ");
         match api::client::check_database(config).await {
-            Ok(_) => println!("{}", "✓ Connected".green()),
-            Err(e) => println!("{} {}", "✗".red(), e),
+            Ok(_) => println!("{}", "✓ Connected".success()),
+            Err(e) => ui::fail(e),
         }
 
-        // Check GCS
+        // Check GCS; with the core API up, a failure here is a partial outage
         print!("  Checking GCS Context... ");
         match api::client::check_gcs(config).await {
-            Ok(count) => println!("{} {} files available", "✓".green(), count),
-            Err(e) => println!("{} {}", "✗".red(), e),
+            Ok(count) => println!("{} {} files available", "✓".success(), count),
+            Err(e) if api_up => {
+                ui::mark_failed();
+                println!("{} Unavailable: {}", "⚠".warning(), e);
+                println!("\n{} Core API is up, context subsystem is down", "⚠".warning());
+                println!("  Memory, skills, and chat still work; context commands fail until it recovers.");
+                if api::client::is_context_outage(&e) {
+                    println!("  {} {}", "→".dimmed(), "Check the backend's GCS credentials".dimmed());
+                }
+            }
+            Err(e) => ui::fail(e),
         }
     }
 
     Ok(())
 }
 
-fn handle_config(action: ConfigAction, config: &config::Config) -> Result<()> {
+/// Poll the deep health check, redrawing a single status line.
+///
+/// Rings the terminal bell when the backend goes from healthy to unhealthy.
+async fn watch_health(secs: u64, config: &config::Config, quiet: bool) -> Result<()> {
+    use std::io::Write;
+
+    ui::header(&format!("PAM Health Watch: {} (every {}s, Ctrl-C to stop)", config.api_url, secs), quiet);
+    ui::install_interrupt_handler();
+
+    let dot = |ok: bool| if ok { "●".success() } else { "●".error() };
+    let interval = std::time::Duration::from_secs(secs.max(1));
+    let mut last: Option<(bool, bool, bool)> = None;
+    let mut changed_at = chrono::Local::now();
+
+    loop {
+        let (api, db, gcs) = tokio::join!(
+            api::client::health_check(&config.api_url),
+            api::client::check_database(config),
+            api::client::check_gcs(config),
+        );
+        let api = api.is_ok_and(|h| h.status == "Healthy");
+        let state = (api, db.is_ok(), gcs.is_ok());
+        let healthy = state == (true, true, true);
+        let degraded = state == (true, true, false);
+
+        let now = chrono::Local::now();
+        if last != Some(state) {
+            let was_healthy = last.is_some_and(|prev| prev == (true, true, true));
+            if was_healthy && !healthy {
+                print!("\x07");
+            }
+            if last.is_some() {
+                changed_at = now;
+            }
+            last = Some(state);
+        }
+
+        print!(
+            "\r\x1B[2K{} api {} db {} gcs  {}  checked {}  last change {}",
+            dot(state.0),
+            dot(state.1),
+            dot(state.2),
+            match (healthy, degraded) {
+                (true, _) => "healthy".success(),
+                (_, true) => "degraded (context down)".warning(),
+                _ => "unhealthy".error(),
+            },
+            now.format("%H:%M:%S"),
+            changed_at.format("%H:%M:%S"),
+        );
+        std::io::stdout().flush()?;
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+async fn whoami(verify: bool, config: &config::Config, config_flag: Option<&str>, quiet: bool) -> Result<()> {
+    ui::header("PAM Identity", quiet);
+
+    let user = config.user_email_source();
+    match &user {
+        Some((email, source)) => println!("User:    {} ({})", email.bold(), source),
+        None => println!(
+            "User:    {} - commands fall back to unknown@mergeworld.com",
+            "not set".warning()
+        ),
+    }
+
+    let key_source = if std::env::var("PAM_CLI_API_KEY").is_ok() { "PAM_CLI_API_KEY" } else { "config" };
+    match &config.cli_api_key {
+        Some(key) if !key.is_empty() => println!("API key: {} ({})", mask_key(key), key_source),
+        _ => println!("API key: {}", "not set".warning()),
+    }
+
+    let url_source = if std::env::var("PAM_API_URL").is_ok() {
+        "PAM_API_URL"
+    } else if config.api_url == config::Config::default().api_url {
+        "default"
+    } else {
+        "config"
+    };
+    println!("API URL: {} ({})", config.api_url, url_source);
+
+    let config_path = match config_flag {
+        Some(path) => std::path::PathBuf::from(path),
+        None => config::Config::config_path()?,
+    };
+    let exists = if config_path.exists() { "" } else { " (not created yet)" };
+    println!("Config:  {}{}", config_path.display(), exists);
+    if let Some(project) = config.sources.iter().find(|p| **p != config_path) {
+        println!("Project: {}", project.display());
+    }
+
+    if verify {
+        let email = user.map(|(email, _)| email).unwrap_or_else(|| "unknown@mergeworld.com".to_string());
+        print!("\nVerifying API key... ");
+        match api::client::verify_cli_key(&config.api_url, &email).await {
+            Ok(Some(true)) => println!("{}", "✓ accepted".success()),
+            Ok(Some(false)) => ui::fail("rejected by the backend"),
+            Ok(None) => println!("{} backend has no verification endpoint", "⚠".warning()),
+            Err(e) => ui::fail(e),
+        }
+    }
+
+    Ok(())
+}
+
+/// Show only the last few characters of a secret
+fn mask_key(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() < 12 {
+        return "********".to_string();
+    }
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("********{}", tail)
+}
+
+async fn print_version(config: &config::Config, verbose: bool) -> Result<()> {
+    println!("pam {}", env!("CARGO_PKG_VERSION"));
+    if !verbose {
+        return Ok(());
+    }
+
+    println!("Commit:  {}", env!("VERGEN_GIT_SHA"));
+    println!("Rustc:   {}", env!("VERGEN_RUSTC_SEMVER"));
+
+    let backend = match api::client::health_check(&config.api_url).await {
+        Ok(health) => health.version.unwrap_or_else(|| format!("unknown ({})", health.status)),
+        Err(e) => format!("unreachable ({})", e),
+    };
+    println!("Backend: {} at {}", backend, config.api_url);
+
+    Ok(())
+}
+
+fn handle_config(action: ConfigAction, config: &config::Config, quiet: bool) -> Result<()> {
     match action {
-        ConfigAction::Show => {
-            println!("{}", "PAM Configuration".bold());
-            println!("{}", "─".repeat(40));
+        ConfigAction::Show { format: Some(format) } => ui::print_data(&config.masked(), format),
+        ConfigAction::Show { format: None } => {
+            ui::header("PAM Configuration", quiet);
             println!("API URL:     {}", config.api_url);
             println!("GCS Bucket:  {}", config.gcs_bucket);
             println!("User Email:  {}", config.user_email.as_deref().unwrap_or("(not set)"));
             println!("DB Host:     {}", config.db_host);
+            println!("Timeout:     {}s", config.timeout().as_secs());
+            println!("Parallel:    {} requests", config.concurrency());
+            match config.default_limit {
+                Some(n) => println!("List limit:  {}", n),
+                None => println!("List limit:  (per-command default)"),
+            }
+            println!("Compression: {}", if config.compress_requests { "requests + responses" } else { "responses" });
+            println!("Theme:       {}", config.theme.name.as_deref().unwrap_or("default"));
             Ok(())
         }
         ConfigAction::Set { key, value } => {
             println!("Setting {} = {}", key.bold(), value);
             config::Config::set_value(&key, &value)?;
-            println!("{} Configuration updated", "✓".green());
+            println!("{} Configuration updated", "✓".success());
             Ok(())
         }
-        ConfigAction::Init { force } => {
-            config::Config::init(force)?;
-            println!("{} Configuration initialized", "✓".green());
+        ConfigAction::Init { force, interactive } => {
+            config::Config::init(force, interactive)?;
+            println!("{} Configuration initialized", "✓".success());
             Ok(())
         }
         ConfigAction::Path => {
             println!("{}", config::Config::config_path()?.display());
             Ok(())
         }
+        ConfigAction::Export { path, no_secrets } => {
+            match config::Config::export(&path, !no_secrets) {
+                Ok(()) if no_secrets => {
+                    println!("{} Exported config to {} (secrets omitted)", "✓".success(), path.display());
+                }
+                Ok(()) => {
+                    println!("{} Exported config to {}", "✓".success(), path.display());
+                    println!("{} It may contain secrets; use --no-secrets before sharing it", "⚠".warning());
+                }
+                Err(e) => ui::fail_error("Export failed", &e),
+            }
+            Ok(())
+        }
+        ConfigAction::Import { path } => {
+            match config::Config::import(&path) {
+                Ok(changed) if changed.is_empty() => {
+                    println!("{} Nothing to import: your config already matches {}", "✓".success(), path.display());
+                }
+                Ok(changed) => {
+                    for change in &changed {
+                        let (old, new) = if change.is_secret() {
+                            (change.old.as_ref().map(|_| "********".to_string()), "********".to_string())
+                        } else {
+                            (change.old.clone(), change.new.clone())
+                        };
+                        let old = old.as_deref().unwrap_or("(unset)");
+                        println!("  {}: {} → {}", change.key.bold(), old.dimmed(), new);
+                    }
+                    println!("{} Imported {} setting(s) from {}", "✓".success(), changed.len(), path.display());
+                }
+                Err(e) => ui::fail_error("Import failed", &e),
+            }
+            Ok(())
+        }
     }
 }
@@ -0,0 +1,163 @@
+//! Color theme for terminal output, configured under `[theme]` in config.toml
+
+use std::sync::OnceLock;
+
+use colored::{Color, ColoredString, Colorize};
+use serde::{Deserialize, Serialize};
+
+/// The `[theme]` config section.
+///
+/// `name` picks a built-in palette; any role set here overrides it. Colors
+/// are names (`cyan`, `bright green`) or hex (`#5fafff`), or `none` for
+/// plain text.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    /// Built-in theme: default, dark, light, or mono
+    pub name: Option<String>,
+    pub primary: Option<String>,
+    pub success: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Colors for each output role; `None` leaves text uncolored
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub primary: Option<Color>,
+    pub success: Option<Color>,
+    pub warning: Option<Color>,
+    pub error: Option<Color>,
+}
+
+/// Names accepted by `theme.name`
+pub const BUILT_IN: [&str; 4] = ["default", "dark", "light", "mono"];
+
+impl Theme {
+    /// A built-in theme by name
+    pub fn built_in(name: &str) -> Option<Theme> {
+        let theme = match name.to_lowercase().as_str() {
+            "default" => Theme {
+                primary: Some(Color::Cyan),
+                success: Some(Color::Green),
+                warning: Some(Color::Yellow),
+                error: Some(Color::Red),
+            },
+            // Brighter variants stay readable on dark backgrounds
+            "dark" => Theme {
+                primary: Some(Color::BrightCyan),
+                success: Some(Color::BrightGreen),
+                warning: Some(Color::BrightYellow),
+                error: Some(Color::BrightRed),
+            },
+            // Yellow and cyan wash out on white, so use deeper tones
+            "light" => Theme {
+                primary: Some(Color::Blue),
+                success: Some(Color::TrueColor { r: 0, g: 128, b: 0 }),
+                warning: Some(Color::TrueColor { r: 175, g: 95, b: 0 }),
+                error: Some(Color::TrueColor { r: 175, g: 0, b: 0 }),
+            },
+            "mono" => Theme {
+                primary: None,
+                success: None,
+                warning: None,
+                error: None,
+            },
+            _ => return None,
+        };
+        Some(theme)
+    }
+
+    /// Build the theme from config, falling back to defaults for unknown names and colors
+    pub fn from_config(config: &ThemeConfig) -> Theme {
+        let name = config.name.as_deref().unwrap_or("default");
+        let mut theme = Theme::built_in(name).unwrap_or_else(|| {
+            tracing::warn!("Unknown theme '{}' (expected one of: {}), using default", name, BUILT_IN.join(", "));
+            Theme::default()
+        });
+
+        let roles = [
+            ("primary", &config.primary, &mut theme.primary),
+            ("success", &config.success, &mut theme.success),
+            ("warning", &config.warning, &mut theme.warning),
+            ("error", &config.error, &mut theme.error),
+        ];
+        for (role, value, slot) in roles {
+            let Some(value) = value else { continue };
+            match parse_color(value) {
+                Some(color) => *slot = color,
+                None => tracing::warn!("Unknown theme color '{}' for {}, keeping the theme's", value, role),
+            }
+        }
+        theme
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::built_in("default").expect("default theme exists")
+    }
+}
+
+/// Parse a color name or `#rrggbb`; `Some(None)` means "no color"
+fn parse_color(value: &str) -> Option<Option<Color>> {
+    let value = value.trim();
+    if value.eq_ignore_ascii_case("none") {
+        return Some(None);
+    }
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+        return Some(Some(Color::TrueColor { r: channel(0)?, g: channel(2)?, b: channel(4)? }));
+    }
+    value.replace(['_', '-'], " ").parse().ok().map(Some)
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// Install the theme for this run; call once at startup
+pub fn set(theme: Theme) {
+    let _ = THEME.set(theme);
+}
+
+fn current() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+fn paint<T: Colorize>(text: T, color: Option<Color>) -> ColoredString {
+    match color {
+        Some(color) => text.color(color),
+        None => text.normal(),
+    }
+}
+
+/// Color text by its role in the output, using the configured theme
+pub trait Themed {
+    /// Headings, labels, and informational markers
+    fn primary(self) -> ColoredString;
+    /// Success marks and healthy states
+    fn success(self) -> ColoredString;
+    /// Warnings and degraded states
+    fn warning(self) -> ColoredString;
+    /// Failures
+    fn error(self) -> ColoredString;
+}
+
+impl<T: Colorize> Themed for T {
+    fn primary(self) -> ColoredString {
+        paint(self, current().primary)
+    }
+
+    fn success(self) -> ColoredString {
+        paint(self, current().success)
+    }
+
+    fn warning(self) -> ColoredString {
+        paint(self, current().warning)
+    }
+
+    fn error(self) -> ColoredString {
+        paint(self, current().error)
+    }
+}
@@ -0,0 +1,444 @@
+//! Terminal output helpers shared across commands
+
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use colored::Colorize;
+use crate::theme::Themed;
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Print a bold section title followed by a separator rule.
+///
+/// Suppressed entirely in quiet mode.
+pub fn header(title: &str, quiet: bool) {
+    if quiet {
+        return;
+    }
+    println!("{}", title.bold());
+    println!("{}", "─".repeat(40));
+}
+
+/// Set once any command reports a failure; `--strict` turns it into exit code 1
+static FAILED: AtomicBool = AtomicBool::new(false);
+
+/// Set when the command prints JSON, so failures are printed as JSON too
+static JSON_ERRORS: AtomicBool = AtomicBool::new(false);
+
+/// Report failures as `{"error": {"kind", "message"}}` objects for the rest of the run
+pub fn use_json_errors() {
+    JSON_ERRORS.store(true, Ordering::Relaxed);
+}
+
+/// Whether failures are being reported as JSON
+pub fn json_errors() -> bool {
+    JSON_ERRORS.load(Ordering::Relaxed)
+}
+
+/// Report a failed operation as `✗ message` and remember it for `--strict`
+pub fn fail(message: impl std::fmt::Display) {
+    mark_failed();
+    if json_errors() {
+        print_json_error("error", &message.to_string());
+    } else {
+        println!("{} {}", "✗".error(), message);
+    }
+}
+
+/// [`fail`] with the error that caused it, so JSON output can carry its
+/// kind (e.g. `not_found`, `unauthorized`, `network`)
+pub fn fail_error(context: &str, error: &anyhow::Error) {
+    mark_failed();
+    let message = format!("{}: {}", context, error);
+    if json_errors() {
+        print_json_error(crate::api::client::error_kind(error), &message);
+    } else {
+        println!("{} {}", "✗".error(), message);
+    }
+}
+
+fn print_json_error(kind: &str, message: &str) {
+    println!("{}", serde_json::json!({ "error": { "kind": kind, "message": message } }));
+}
+
+/// Remember a failure that was reported some other way
+pub fn mark_failed() {
+    FAILED.store(true, Ordering::Relaxed);
+}
+
+/// Whether any failure has been reported during this run
+pub fn failed() -> bool {
+    FAILED.load(Ordering::Relaxed)
+}
+
+/// Set once the clock-skew warning has been shown
+static CLOCK_SKEW_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Warn (once per run, on stderr) that a timestamp lies in the future,
+/// which almost always means the local clock is wrong.
+pub fn warn_clock_skew() {
+    if !CLOCK_SKEW_WARNED.swap(true, Ordering::Relaxed) {
+        eprintln!(
+            "{} Some timestamps are in the future; check your system clock",
+            "⚠".warning()
+        );
+    }
+}
+
+/// Start an animated spinner for a slow API call.
+///
+/// Hidden in quiet mode or when output is redirected, so piped output stays
+/// clean. Call `finish_and_clear()` once the call returns.
+pub fn spinner(message: &str, quiet: bool) -> ProgressBar {
+    styled_spinner("{spinner:.cyan} {msg}", message, quiet)
+}
+
+/// A [`spinner`] that also shows the elapsed time, for calls that can run
+/// for a minute or more (e.g. generating a reflection).
+pub fn timed_spinner(message: &str, quiet: bool) -> ProgressBar {
+    styled_spinner("{spinner:.cyan} {msg} {elapsed:.dim}", message, quiet)
+}
+
+fn styled_spinner(template: &str, message: &str, quiet: bool) -> ProgressBar {
+    if quiet || !std::io::stdout().is_terminal() || !std::io::stderr().is_terminal() {
+        return ProgressBar::hidden();
+    }
+
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::with_template(template)
+            .expect("valid spinner template")
+            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏ "),
+    );
+    spinner.set_message(message.to_string());
+    spinner.enable_steady_tick(Duration::from_millis(100));
+    spinner
+}
+
+/// Whether `PAM_ASSUME_YES` is set to a truthy value (1/true/yes/y/on).
+///
+/// This auto-confirms every prompt, including destructive ones like
+/// `memory clear`, so only set it in trusted automation.
+pub fn assume_yes() -> bool {
+    std::env::var("PAM_ASSUME_YES")
+        .map(|v| matches!(v.trim().to_lowercase().as_str(), "1" | "true" | "yes" | "y" | "on"))
+        .unwrap_or(false)
+}
+
+/// Ask a yes/no question, auto-confirming when `PAM_ASSUME_YES` is set
+pub fn confirm(prompt: &str, default: bool) -> anyhow::Result<bool> {
+    if assume_yes() {
+        println!("{} {} (auto-confirmed via PAM_ASSUME_YES)", "⚠".warning(), prompt);
+        return Ok(true);
+    }
+
+    Ok(dialoguer::Confirm::new()
+        .with_prompt(prompt)
+        .default(default)
+        .interact()?)
+}
+
+/// Exit code for a run cut short by Ctrl-C (128 + SIGINT)
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// On Ctrl-C, tidy the terminal and exit with code 130.
+///
+/// Clears any half-drawn spinner line, restores the cursor (hidden by
+/// prompts and spinners), and ends on a fresh line instead of mid-output.
+pub fn install_interrupt_handler() {
+    tokio::spawn(async {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            restore_terminal();
+            std::process::exit(INTERRUPTED_EXIT_CODE);
+        }
+    });
+}
+
+fn restore_terminal() {
+    use std::io::Write;
+
+    if std::io::stderr().is_terminal() {
+        let mut stderr = std::io::stderr();
+        let _ = write!(stderr, "\r\x1B[2K\x1B[?25h");
+        let _ = stderr.flush();
+    }
+    println!();
+}
+
+/// Show `text` through `$PAGER` (default `less -R`) when stdout is a terminal,
+/// printing it directly when piped or when the pager can't be started.
+pub fn page(text: &str) {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    if !std::io::stdout().is_terminal() {
+        println!("{}", text);
+        return;
+    }
+
+    let pager = std::env::var("PAGER").unwrap_or_else(|_| "less -R".to_string());
+    let mut parts = pager.split_whitespace();
+    let Some(program) = parts.next() else {
+        println!("{}", text);
+        return;
+    };
+
+    match Command::new(program).args(parts).stdin(Stdio::piped()).spawn() {
+        Ok(mut child) => {
+            if let Some(mut stdin) = child.stdin.take() {
+                // The pager closing early (e.g. `q` in less) is not an error
+                let _ = stdin.write_all(text.as_bytes());
+            }
+            let _ = child.wait();
+        }
+        Err(e) => {
+            tracing::debug!("Could not start pager '{}': {}", pager, e);
+            println!("{}", text);
+        }
+    }
+}
+
+/// Terminal width in columns, or 80 when it can't be detected (e.g. piped output)
+pub fn terminal_width() -> usize {
+    ratatui::crossterm::terminal::size()
+        .map(|(cols, _)| cols as usize)
+        .unwrap_or(80)
+}
+
+/// Print a status line, on stderr when stdout carries only the result
+/// (e.g. `--format json`)
+pub fn note(to_stderr: bool, line: impl std::fmt::Display) {
+    if to_stderr {
+        eprintln!("{}", line);
+    } else {
+        println!("{}", line);
+    }
+}
+
+/// A count, or "unknown" when it couldn't be determined
+pub fn count_or_unknown(count: Option<i64>) -> String {
+    count.map_or_else(|| "unknown".to_string(), |n| n.to_string())
+}
+
+/// Cut `s` to at most `max` characters, never splitting a UTF-8 character,
+/// and mark the cut with an ellipsis.
+pub fn truncate_display(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        return s.to_string();
+    }
+    let mut truncated: String = s.chars().take(max.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Flatten `s` to one line that fits the terminal after `indent` columns
+pub fn preview_line(s: &str, indent: usize) -> String {
+    let flat = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    truncate_display(&flat, terminal_width().saturating_sub(indent).max(20))
+}
+
+/// An empty result a command can report, paired with the next step to suggest
+#[derive(Debug, Clone, Copy)]
+pub enum Empty {
+    SearchResults,
+    Memories,
+    TaggedMemories,
+    SkillLog,
+    FavoriteSkills,
+    FilteredSkills,
+    SavedParams,
+    ChatSessions,
+    ContextHistory,
+    JiraTickets,
+}
+
+impl Empty {
+    fn message(self) -> &'static str {
+        match self {
+            Empty::SearchResults => "No memories found.",
+            Empty::Memories => "No memories found.",
+            Empty::TaggedMemories => "No tagged memories.",
+            Empty::SkillLog => "No log entries found.",
+            Empty::FavoriteSkills => "No favorite skills yet.",
+            Empty::FilteredSkills => "No skills match the filters.",
+            Empty::SavedParams => "No saved skill params.",
+            Empty::ChatSessions => "No chat sessions recorded yet.",
+            Empty::ContextHistory => "No history recorded yet.",
+            Empty::JiraTickets => "No tickets found matching criteria.",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            Empty::SearchResults => "Try broader terms, or add memories with `pam memory index <content>`",
+            Empty::Memories => "Add one with `pam memory index <content>`",
+            Empty::TaggedMemories => "Tag new memories with `pam memory index --tags <tag> <content>`",
+            Empty::SkillLog => "Skill runs are logged here; try `pam skills list` and `pam skills invoke <skill>`",
+            Empty::FavoriteSkills => "Add one with `pam skills fav add <skill>`",
+            Empty::FilteredSkills => "Drop --risk, --enabled, or --disabled to see every skill",
+            Empty::SavedParams => "Save some with `pam skills test <skill> --params <json> --save-default-params`",
+            Empty::ChatSessions => "Start one with `pam chat`",
+            Empty::ContextHistory => "Each `pam context stats` run records a data point",
+            Empty::JiraTickets => "Check the project key with `pam jira projects`",
+        }
+    }
+}
+
+/// Report an empty result with a hint toward the next command (hint hidden with `--quiet`)
+pub fn empty(state: Empty, quiet: bool) {
+    println!("{}", state.message().warning());
+    if !quiet {
+        println!("{} {}", "→".dimmed(), state.hint().dimmed());
+    }
+}
+
+/// Tell the user how to fetch the next page, if there is one
+pub fn next_page_hint(next_cursor: Option<&str>) {
+    if let Some(cursor) = next_cursor {
+        println!("\n{} More available, use --cursor {}", "•".primary(), cursor);
+    }
+}
+
+/// Layout for commands that list many items
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ListFormat {
+    /// One item per line with details underneath
+    #[default]
+    List,
+    /// Aligned columns, truncated to the terminal width
+    Table,
+    /// One JSON object per line, printed as each page arrives (paged lists stream every page)
+    Ndjson,
+    /// Tab-separated rows under a header line, no colors (paged lists stream every page)
+    Tsv,
+}
+
+/// Format for commands that print one structured value
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum DataFormat {
+    /// Pretty-printed JSON
+    Json,
+    /// YAML, easier to read for nested results
+    Yaml,
+}
+
+/// Print `value` as pretty JSON or YAML
+pub fn print_data<T: serde::Serialize + ?Sized>(value: &T, format: DataFormat) -> anyhow::Result<()> {
+    match format {
+        DataFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+        DataFormat::Yaml => print!("{}", serde_yaml::to_string(value)?),
+    }
+    Ok(())
+}
+
+/// Print each item of every page as one JSON line, following `next_cursor`
+/// from `cursor` until the last page.
+///
+/// Nothing else is written to stdout, so the output can go straight into
+/// `jq -c`. A failed page is reported on stderr and ends the stream.
+pub async fn stream_ndjson<T, F, Fut>(cursor: Option<String>, fetch: F) -> anyhow::Result<()>
+where
+    T: serde::Serialize,
+    F: FnMut(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<crate::api::client::Page<T>>>,
+{
+    stream_pages(cursor, fetch, |item| Ok(serde_json::to_string(item)?)).await
+}
+
+/// Print a header row, then one tab-separated row per item of every page,
+/// following `next_cursor` like [`stream_ndjson`].
+///
+/// Fields are escaped with [`tsv_field`], so each item is exactly one line.
+pub async fn stream_tsv<T, F, Fut>(
+    headers: &[&str],
+    cursor: Option<String>,
+    fetch: F,
+    row: impl Fn(&T) -> Vec<String>,
+) -> anyhow::Result<()>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<crate::api::client::Page<T>>>,
+{
+    println!("{}", headers.join("\t"));
+    stream_pages(cursor, fetch, |item| Ok(tsv_row(&row(item)))).await
+}
+
+/// Write `line(item)` for each item of every page; see [`stream_ndjson`]
+async fn stream_pages<T, F, Fut>(
+    cursor: Option<String>,
+    mut fetch: F,
+    line: impl Fn(&T) -> anyhow::Result<String>,
+) -> anyhow::Result<()>
+where
+    F: FnMut(Option<String>) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<crate::api::client::Page<T>>>,
+{
+    use std::io::Write;
+
+    let mut cursor = cursor;
+    loop {
+        let page = match fetch(cursor.take()).await {
+            Ok(page) => page,
+            Err(e) if json_errors() => {
+                fail_error("Failed to fetch results", &e);
+                return Ok(());
+            }
+            Err(e) => {
+                mark_failed();
+                eprintln!("{} {}", "✗".error(), e);
+                return Ok(());
+            }
+        };
+
+        let mut out = std::io::stdout().lock();
+        for item in &page.items {
+            writeln!(out, "{}", line(item)?)?;
+        }
+        out.flush()?;
+
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => return Ok(()),
+        }
+    }
+}
+
+/// Escape backslashes, tabs, and line breaks so a value fits in one TSV cell
+pub fn tsv_field(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Join escaped fields into one tab-separated line
+pub fn tsv_row(fields: &[String]) -> String {
+    fields.iter().map(|f| tsv_field(f)).collect::<Vec<_>>().join("\t")
+}
+
+/// Build a table sized to the terminal with one line per row.
+///
+/// Rows added with [`table_row`] are cut to a single line, so long cells are
+/// truncated rather than wrapped.
+pub fn table(headers: &[&str]) -> comfy_table::Table {
+    let mut table = comfy_table::Table::new();
+    table
+        .load_preset(comfy_table::presets::UTF8_FULL_CONDENSED)
+        .set_content_arrangement(comfy_table::ContentArrangement::Dynamic)
+        .set_header(headers.to_vec());
+    table
+}
+
+/// Append a row that never grows past one line
+pub fn table_row<T: Into<comfy_table::Cell>>(table: &mut comfy_table::Table, cells: Vec<T>) {
+    let mut row = comfy_table::Row::from(cells);
+    row.max_height(1);
+    table.add_row(row);
+}